@@ -6,16 +6,14 @@
 
 use std::io::Result;
 
-// Replace the committed protobuf files with the generated one.
+// Paths of the `.proto` files in the given directory.
 #[cfg(feature = "regenerate-protobuf")]
-fn generate_proto_sources() -> Result<()> {
+fn proto_files(path: &str) -> Result<Vec<String>> {
     use std::fs::read_dir;
     use std::io::{Error, ErrorKind};
     use std::path::Path;
-    use std::process::Command;
 
-    let path = String::from("parsec-operations/protobuf");
-    let dir_entries = read_dir(Path::new(&path))?;
+    let dir_entries = read_dir(Path::new(path))?;
     let files: Result<Vec<String>> = dir_entries
         .map(|protos_file| {
             protos_file?
@@ -31,13 +29,40 @@ fn generate_proto_sources() -> Result<()> {
         })
         // Fail the entire operation if there was an error.
         .collect();
-    let proto_files: Vec<String> = files?
+    Ok(files?
         .into_iter()
         .filter(|string| string.ends_with(".proto"))
+        .collect())
+}
+
+// Replace the committed protobuf files with the generated one.
+#[cfg(feature = "regenerate-protobuf")]
+fn generate_proto_sources() -> Result<()> {
+    use std::io::{Error, ErrorKind};
+    use std::path::Path;
+    use std::process::Command;
+
+    let path = String::from("parsec-operations/protobuf");
+    // Definitions not yet in the parsec-operations repository. They replace the ones of the
+    // submodule with the same file name.
+    let local_path = String::from("protobuf");
+
+    let local_files = proto_files(&local_path)?;
+    let local_names: Vec<_> = local_files
+        .iter()
+        .filter_map(|file| Path::new(file).file_name().map(|name| name.to_os_string()))
+        .collect();
+    let mut files: Vec<String> = proto_files(&path)?
+        .into_iter()
+        .filter(|file| match Path::new(file).file_name() {
+            Some(name) => !local_names.iter().any(|local_name| local_name == name),
+            None => true,
+        })
         .collect();
-    let files_slices: Vec<&str> = proto_files.iter().map(|file| &file[..]).collect();
+    files.extend(local_files);
+    let files_slices: Vec<&str> = files.iter().map(|file| &file[..]).collect();
 
-    prost_build::compile_protos(&files_slices, &[&path])?;
+    prost_build::compile_protos(&files_slices, &[&local_path, &path])?;
 
     // Copy all files generated in src/operations_protobuf/generated_ops
     let status = Command::new("bash")
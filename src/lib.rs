@@ -2,7 +2,6 @@
 // SPDX-License-Identifier: Apache-2.0
 #![deny(
     nonstandard_style,
-    const_err,
     dead_code,
     improper_ctypes,
     non_shorthand_field_patterns,
@@ -10,7 +9,6 @@
     overflowing_literals,
     path_statements,
     patterns_in_fns_without_body,
-    private_in_public,
     unconditional_recursion,
     unused,
     unused_allocation,
@@ -43,7 +41,7 @@
 //! This library is used by the Parsec service to:
 //! * read from a stream a `Request` sent to the service with the `read_from_stream` method
 //! * use the `body_to_operation` method of the `Convert` trait on a converter to parse the request
-//! body into a `NativeOperation`
+//!   body into a `NativeOperation`
 //!
 //!```
 //!# use std::io::Read;
@@ -84,7 +82,7 @@
 //! The service can now execute the operation to yield a `NativeResult` and:
 //! * use the `result_to_body` method to serialize the `NativeResult`
 //! * create a `Response` containing the result as its body and write it back to the stream  with
-//! the `write_to_stream` method.
+//!   the `write_to_stream` method.
 //!
 //!```
 //!# use std::io::Write;
@@ -109,6 +107,7 @@
 //!use parsec_interface::operations::{Convert, NativeResult, psa_generate_key::Result};
 //!use parsec_interface::requests::{ProviderId, Opcode, BodyType, Response, ResponseStatus};
 //!use parsec_interface::requests::response::ResponseHeader;
+//!use parsec_interface::requests::common::WireProtocolVersion;
 //!use parsec_interface::operations_protobuf::ProtobufConverter;
 //!
 //!let converter = ProtobufConverter {};
@@ -121,6 +120,9 @@
 //!        content_type: BodyType::Protobuf,
 //!        opcode: Opcode::PsaGenerateKey,
 //!        status: ResponseStatus::Success,
+//!        wire_protocol_version: WireProtocolVersion::V1_0,
+//!        request_id: 0,
+//!        keep_alive: false,
 //!    },
 //!    body: result_body,
 //!};
@@ -132,7 +134,7 @@
 //!
 //! This library is used by the Parsec Rust clients to:
 //! * use the `operation_to_body` method to serialize the `NativeOperation` to be sent as body of a
-//! `Request`
+//!   `Request`
 //! * write it to the stream with the `write_to_stream` method.
 //!
 //!```
@@ -159,6 +161,7 @@
 //!use parsec_interface::operations::{Convert, NativeOperation};
 //!use parsec_interface::requests::{Request, ProviderId, BodyType, AuthType, Opcode};
 //!use parsec_interface::requests::request::{RequestHeader, RequestAuth};
+//!use parsec_interface::requests::common::WireProtocolVersion;
 //!use parsec_interface::operations_protobuf::ProtobufConverter;
 //!use parsec_interface::operations::ping::Operation;
 //!
@@ -172,6 +175,9 @@
 //!        accept_type: BodyType::Protobuf,
 //!        auth_type: AuthType::Direct,
 //!        opcode: Opcode::Ping,
+//!        wire_protocol_version: WireProtocolVersion::V1_0,
+//!        request_id: 0,
+//!        keep_alive: false,
 //!    },
 //!    body: converter.operation_to_body(operation).unwrap(),
 //!    auth: RequestAuth::new(Vec::from("root")),
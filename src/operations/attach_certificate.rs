@@ -0,0 +1,116 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # AttachCertificate operation
+//!
+//! Associate a chain of X.509 certificates to a key. The chain is removed when the key is
+//! destroyed, including when its owner is deleted with the `DeleteClient` operation.
+
+use super::psa_key_attributes::{Attributes, Type};
+use crate::requests::ResponseStatus;
+
+/// Native object for attaching a certificate chain to a key.
+#[derive(Clone, Debug)]
+pub struct Operation {
+    /// Name of the key the certificate chain is attached to.
+    pub key_name: String,
+    /// DER-encoded certificates, starting with the certificate of the key and followed by the
+    /// certificates of the issuers.
+    pub chain: Vec<Vec<u8>>,
+    /// Replace the certificate chain already attached to the key, if any. If not set, the
+    /// operation fails when the key already has a certificate chain.
+    pub replace: bool,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the key is an asymmetric key
+    /// * the chain contains at least one certificate, and none of them is empty
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        let key_type = key_attributes.key_type;
+        if !(key_type == Type::RsaKeyPair
+            || key_type.is_ecc_key_pair()
+            || key_type.is_dh_key_pair()
+            || key_type.is_public_key())
+        {
+            return Err(ResponseStatus::PsaErrorNotSupported);
+        }
+
+        if self.chain.is_empty() || self.chain.iter().any(Vec::is_empty) {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+
+        Ok(())
+    }
+}
+
+/// Native object for the result of attaching a certificate chain to a key.
+///
+/// The true result is returned in the `status` field of the response.
+#[derive(Copy, Clone, Debug)]
+pub struct Result;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, Hash};
+    use crate::operations::psa_key_attributes::{EccFamily, Lifetime, Policy, UsageFlags};
+
+    fn get_attrs() -> Attributes {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_sign_hash();
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::EccKeyPair {
+                curve_family: EccFamily::SecpR1,
+            },
+            bits: 256,
+            policy: Policy {
+                usage_flags,
+                permitted_algorithms: Algorithm::AsymmetricSignature(AsymmetricSignature::Ecdsa {
+                    hash_alg: Hash::Sha256.into(),
+                }),
+            },
+        }
+    }
+
+    fn get_op() -> Operation {
+        Operation {
+            key_name: String::from("some key"),
+            chain: vec![vec![0x30, 0x82, 0x01], vec![0x30, 0x82, 0x02]],
+            replace: false,
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op().validate(get_attrs()).unwrap();
+    }
+
+    #[test]
+    fn symmetric_key() {
+        let mut attrs = get_attrs();
+        attrs.key_type = Type::Aes;
+        assert_eq!(
+            get_op().validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorNotSupported
+        );
+    }
+
+    #[test]
+    fn invalid_chain() {
+        let mut op = get_op();
+        op.chain = Vec::new();
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+
+        op.chain = vec![vec![0x30], Vec::new()];
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # AttestKey operation
+//!
+//! Produce an attestation token as proof that the given
+//! key was produced and is stored in the hardware backend.
+use derivative::Derivative;
+
+/// Native operation for producing an attestation token for `attested_key_name`
+#[derive(Debug)]
+pub struct Operation {
+    /// Name of key to be attested
+    pub attested_key_name: String,
+    /// Parameters specific to the attestation mechanism
+    pub parameters: MechanismParams,
+    /// Name of key to be used for attesting
+    pub attesting_key_name: Option<String>,
+}
+
+/// Parameters specific to an attestation mechanism
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[non_exhaustive]
+pub enum MechanismParams {
+    /// Parameters for the TPM 2.0 ActivateCredential mechanism
+    ActivateCredential {
+        /// Credential blob, encrypted by the verifier for the attesting key
+        #[derivative(Debug = "ignore")]
+        credential: zeroize::Zeroizing<Vec<u8>>,
+        /// Secret seed used by the verifier to protect the credential
+        #[derivative(Debug = "ignore")]
+        secret: zeroize::Zeroizing<Vec<u8>>,
+    },
+    /// Parameters for a generic certification of the attested key by the attesting key
+    Certify {
+        /// Challenge provided by the verifier, to be included in the attestation
+        nonce: Vec<u8>,
+    },
+}
+
+/// Native result of the attestation operation
+#[derive(Debug)]
+pub struct Result {
+    /// Mechanism-specific output
+    pub output: MechanismResult,
+}
+
+/// Output specific to an attestation mechanism
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[non_exhaustive]
+pub enum MechanismResult {
+    /// Result of the TPM 2.0 ActivateCredential mechanism
+    ActivateCredential {
+        /// Decrypted credential
+        #[derivative(Debug = "ignore")]
+        credential: zeroize::Zeroizing<Vec<u8>>,
+    },
+    /// Result of a generic certification of the attested key
+    Certify {
+        /// Attestation statement describing the attested key and including the nonce
+        attestation: Vec<u8>,
+        /// Signature of the attestation statement, produced with the attesting key
+        signature: Vec<u8>,
+    },
+}
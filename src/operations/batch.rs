@@ -0,0 +1,33 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # Batch operation
+//!
+//! Execute an ordered list of operations in a single request.
+use super::{NativeOperation, NativeResult};
+use crate::requests::{Opcode, ResponseStatus};
+
+/// Native object for batch operation.
+///
+/// Batches can not contain other batches, nor core or admin operations.
+#[derive(Debug)]
+pub struct Operation {
+    /// The operations to execute, in order.
+    pub operations: Vec<NativeOperation>,
+}
+
+/// Operation of a batch that failed.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ItemFailure {
+    /// Opcode of the failed operation.
+    pub opcode: Opcode,
+    /// Status of the failed operation.
+    pub status: ResponseStatus,
+}
+
+/// Native object for batch result.
+#[derive(Debug)]
+pub struct Result {
+    /// The outcome of each operation of the batch, in the same order as in the operation. Failed
+    /// operations do not prevent the following ones from being executed.
+    pub results: Vec<std::result::Result<NativeResult, ItemFailure>>,
+}
@@ -0,0 +1,41 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # CanDoCrypto operation
+//!
+//! Checks if the provider supports the input attributes for the operations of a given type.
+
+use super::psa_key_attributes::Attributes;
+use crate::requests::ProviderId;
+
+/// Types of operation that can be checked against a set of attributes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CheckType {
+    /// Using a key with the given attributes in the permitted algorithm.
+    Use,
+    /// Generating a key with the given attributes.
+    Generate,
+    /// Importing a key with the given attributes.
+    Import,
+    /// Deriving a key with the given attributes.
+    Derive,
+}
+
+/// Native object for the capability check operation.
+#[derive(Copy, Clone, Debug)]
+pub struct Operation {
+    /// `check_type` specifies the type of operation to check.
+    pub check_type: CheckType,
+    /// `attributes` specifies the key attributes, including the permitted algorithm, to check.
+    pub attributes: Attributes,
+    /// `provider_id` specifies the provider to check. The operation is handled by the core
+    /// provider, so the provider of the request header is not used. If not set, the check
+    /// succeeds if any provider of the service supports the combination.
+    pub provider_id: Option<ProviderId>,
+}
+
+/// Native object for the result of the capability check.
+///
+/// The true result is returned in the `status` field of the response: success if the
+/// provider supports the requested combination, `PsaErrorNotSupported` otherwise.
+#[derive(Copy, Clone, Debug)]
+pub struct Result;
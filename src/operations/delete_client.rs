@@ -3,14 +3,34 @@
 //! # DeleteClient operation
 //!
 //! Delete all data a client own in Parsec.
+use super::list_keys::KeyInfo;
+use crate::requests::ResponseStatus;
 
 /// Native object for client deleting operation.
 #[derive(Clone, Debug)]
 pub struct Operation {
     /// A client application name.
     pub client: String,
+    /// If set, nothing is deleted: the result lists the keys that would be destroyed by the same
+    /// operation without the flag.
+    pub dry_run: bool,
+}
+
+/// Key that could not be destroyed while deleting a client.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyDeletionFailure {
+    /// The key that could not be destroyed.
+    pub key: KeyInfo,
+    /// Status of the failed destruction.
+    pub status: ResponseStatus,
 }
 
 /// Native object for client deleting result.
-#[derive(Copy, Clone, Debug)]
-pub struct Result;
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Result {
+    /// The keys that were destroyed, or would be destroyed in dry-run mode. The certificate
+    /// chains attached to these keys are removed with them.
+    pub deleted_keys: Vec<KeyInfo>,
+    /// The keys that could not be destroyed. Always empty in dry-run mode.
+    pub failures: Vec<KeyDeletionFailure>,
+}
@@ -0,0 +1,227 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # GenerateCsr operation
+//!
+//! Generate a PKCS#10 certificate signing request for the public part of a key pair, signed with
+//! its private part.
+
+use super::psa_key_attributes::Attributes;
+use crate::operations::psa_algorithm::AsymmetricSignature;
+use crate::requests::ResponseStatus;
+use std::net::IpAddr;
+
+/// Attribute of the distinguished name of the subject.
+#[derive(Clone, Debug, PartialEq)]
+pub enum NameAttribute {
+    /// Common name (CN)
+    CommonName(String),
+    /// Organization name (O)
+    Organization(String),
+    /// Organizational unit name (OU)
+    OrganizationalUnit(String),
+    /// Two-letter ISO 3166 country code (C)
+    Country(String),
+    /// State or province name (ST)
+    StateOrProvince(String),
+    /// Locality name (L)
+    Locality(String),
+}
+
+impl NameAttribute {
+    fn value(&self) -> &str {
+        match self {
+            NameAttribute::CommonName(value)
+            | NameAttribute::Organization(value)
+            | NameAttribute::OrganizationalUnit(value)
+            | NameAttribute::Country(value)
+            | NameAttribute::StateOrProvince(value)
+            | NameAttribute::Locality(value) => value,
+        }
+    }
+}
+
+/// Alternative name of the subject, added to the request in a subject alternative name extension.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SubjectAltName {
+    /// DNS name
+    DnsName(String),
+    /// IP address
+    IpAddress(IpAddr),
+    /// Email address
+    Email(String),
+    /// Uniform resource identifier
+    Uri(String),
+}
+
+/// Native object for certificate signing request generation.
+#[derive(Clone, Debug)]
+pub struct Operation {
+    /// Name of the key pair to generate the request for.
+    pub key_name: String,
+    /// Distinguished name of the subject, in the order in which its attributes appear in the
+    /// request.
+    pub subject: Vec<NameAttribute>,
+    /// Alternative names of the subject. No subject alternative name extension is added to the
+    /// request if empty.
+    pub subject_alt_names: Vec<SubjectAltName>,
+    /// Algorithm used to sign the request.
+    pub alg: AsymmetricSignature,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the key policy allows signing messages
+    /// * the key policy allows the signing algorithm requested in the operation
+    /// * the key type is a key pair compatible with the requested algorithm
+    /// * the subject is not empty, none of its attributes or alternative names is empty and the
+    ///   country codes have two letters
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        key_attributes.can_sign_message()?;
+        key_attributes.permits_alg(self.alg.into())?;
+        key_attributes.compatible_with_alg(self.alg.into())?;
+        if key_attributes.key_type.is_public_key() {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+
+        let subject_valid = !self.subject.is_empty()
+            && self.subject.iter().all(|attribute| match attribute {
+                NameAttribute::Country(country) => {
+                    country.len() == 2 && country.chars().all(|c| c.is_ascii_alphabetic())
+                }
+                attribute => !attribute.value().is_empty(),
+            });
+        let alt_names_valid = self.subject_alt_names.iter().all(|name| match name {
+            SubjectAltName::DnsName(name)
+            | SubjectAltName::Email(name)
+            | SubjectAltName::Uri(name) => !name.is_empty(),
+            SubjectAltName::IpAddress(_) => true,
+        });
+        if !(subject_valid && alt_names_valid) {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+
+        Ok(())
+    }
+}
+
+/// Native object for certificate signing request generation result.
+#[derive(Clone, Debug)]
+pub struct Result {
+    /// DER-encoded PKCS#10 certificate signing request.
+    pub csr: Vec<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{Algorithm, Hash};
+    use crate::operations::psa_key_attributes::{EccFamily, Lifetime, Policy, Type, UsageFlags};
+
+    fn get_attrs() -> Attributes {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_sign_hash();
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::EccKeyPair {
+                curve_family: EccFamily::SecpR1,
+            },
+            bits: 256,
+            policy: Policy {
+                usage_flags,
+                permitted_algorithms: Algorithm::AsymmetricSignature(AsymmetricSignature::Ecdsa {
+                    hash_alg: Hash::Sha256.into(),
+                }),
+            },
+        }
+    }
+
+    fn get_op() -> Operation {
+        Operation {
+            key_name: String::from("some key"),
+            subject: vec![
+                NameAttribute::CommonName(String::from("device.example.com")),
+                NameAttribute::Country(String::from("GB")),
+            ],
+            subject_alt_names: vec![
+                SubjectAltName::DnsName(String::from("device.example.com")),
+                SubjectAltName::IpAddress(IpAddr::from([192, 168, 0, 1])),
+            ],
+            alg: AsymmetricSignature::Ecdsa {
+                hash_alg: Hash::Sha256.into(),
+            },
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op().validate(get_attrs()).unwrap();
+    }
+
+    #[test]
+    fn cannot_sign() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags = UsageFlags::default();
+        assert_eq!(
+            get_op().validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wrong_algorithm() {
+        let mut op = get_op();
+        op.alg = AsymmetricSignature::Ecdsa {
+            hash_alg: Hash::Sha384.into(),
+        };
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn public_key() {
+        let mut attrs = get_attrs();
+        attrs.key_type = Type::EccPublicKey {
+            curve_family: EccFamily::SecpR1,
+        };
+        assert_eq!(
+            get_op().validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn invalid_subject() {
+        let mut op = get_op();
+        op.subject = Vec::new();
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+
+        op.subject = vec![NameAttribute::Country(String::from("GBR"))];
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+
+        op.subject = vec![NameAttribute::Organization(String::new())];
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn invalid_alt_name() {
+        let mut op = get_op();
+        op.subject_alt_names = vec![SubjectAltName::Uri(String::new())];
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
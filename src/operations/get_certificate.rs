@@ -0,0 +1,20 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # GetCertificate operation
+//!
+//! Retrieve the chain of X.509 certificates attached to a key.
+
+/// Native object for retrieving the certificate chain of a key.
+#[derive(Clone, Debug)]
+pub struct Operation {
+    /// Name of the key whose certificate chain is requested.
+    pub key_name: String,
+}
+
+/// Native object for the result of retrieving the certificate chain of a key.
+#[derive(Clone, Debug)]
+pub struct Result {
+    /// DER-encoded certificates, starting with the certificate of the key and followed by the
+    /// certificates of the issuers.
+    pub chain: Vec<Vec<u8>>,
+}
@@ -0,0 +1,16 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # ListCertificates operation
+//!
+//! Lists the keys of the application which have a certificate chain attached.
+
+/// Native object for certificate listing operation.
+#[derive(Copy, Clone, Debug)]
+pub struct Operation;
+
+/// Native object for certificate listing result.
+#[derive(Clone, Debug)]
+pub struct Result {
+    /// Names of the keys which have a certificate chain attached.
+    pub key_names: Vec<String>,
+}
@@ -3,14 +3,68 @@
 //! # ListClients operation
 //!
 //! Lists all clients owning data in Parsec.
+use crate::requests::ProviderId;
+use std::collections::HashMap;
 
 /// Native object for client listing operation.
-#[derive(Copy, Clone, Debug)]
-pub struct Operation;
+///
+/// The default value of this structure lists all the clients in a single page.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Operation {
+    /// Continuation token returned in the `next_page_token` field of a previous result, to
+    /// continue listing from where that result stopped.
+    pub page_token: Option<String>,
+    /// Maximum number of clients to return in the result.
+    pub limit: Option<u32>,
+}
+
+/// Structure holding the information about a client owning data in Parsec.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ClientInfo {
+    /// The client application name.
+    pub name: String,
+    /// Number of keys owned by the client in each provider. Providers in which the client does
+    /// not own any key are not listed.
+    pub keys_per_provider: HashMap<ProviderId, u32>,
+    /// Time at which the client was last seen by the service, in seconds since the UNIX epoch,
+    /// if known.
+    pub last_seen: Option<u64>,
+}
+
+impl ClientInfo {
+    /// Total number of keys owned by the client, across all providers.
+    pub fn key_count(&self) -> u64 {
+        self.keys_per_provider
+            .values()
+            .map(|count| u64::from(*count))
+            .sum()
+    }
+}
 
 /// Native object for client listing result.
 #[derive(Debug)]
 pub struct Result {
-    /// A list of client application names.
-    pub clients: Vec<String>,
+    /// The information about the listed clients.
+    pub clients: Vec<ClientInfo>,
+    /// Token to pass in the `page_token` field of the next operation to get the next page of
+    /// clients, `None` if this was the last page.
+    pub next_page_token: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_count() {
+        let mut info = ClientInfo {
+            name: String::from("toto"),
+            ..Default::default()
+        };
+        assert_eq!(info.key_count(), 0);
+
+        let _ = info.keys_per_provider.insert(ProviderId::MbedCrypto, 3);
+        let _ = info.keys_per_provider.insert(ProviderId::Tpm, u32::MAX);
+        assert_eq!(info.key_count(), 3 + u64::from(u32::MAX));
+    }
 }
@@ -3,9 +3,23 @@
 //! # ListKeys operation
 //!
 //! Lists all keys belonging to the application.
-use super::psa_key_attributes::Attributes;
+use super::is_usage_subset;
+use super::psa_key_attributes::{Attributes, Type, UsageFlags};
 use crate::requests::ProviderId;
 
+/// How a key was created.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum KeyOrigin {
+    /// The key was generated by the provider.
+    Generated,
+    /// The key material was imported by the client.
+    Imported,
+    /// The key was derived from another key, or is the output of a key agreement.
+    Derived,
+    /// The key was copied from another key.
+    Copied,
+}
+
 /// Structure holding the basic information for a key in the application for client discovery.
 #[derive(Debug, Clone, PartialEq)]
 pub struct KeyInfo {
@@ -13,17 +27,128 @@ pub struct KeyInfo {
     pub provider_id: ProviderId,
     /// The name of the key.
     pub name: String,
-    /// The key attributes.
+    /// The key attributes. Their `lifetime` field holds the persistence lifetime of the key.
     pub attributes: Attributes,
+    /// Time at which the key was created, in seconds since the UNIX epoch, if known.
+    pub creation_time: Option<u64>,
+    /// How the key was created, if known.
+    pub origin: Option<KeyOrigin>,
 }
 
 /// Native object for key listing operation.
-#[derive(Copy, Clone, Debug)]
-pub struct Operation;
+///
+/// All the filters are optional: the default value of this structure lists all the keys of the
+/// application in a single page.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Operation {
+    /// Only list the keys stored in this provider.
+    pub provider_id: Option<ProviderId>,
+    /// Only list the keys of this type.
+    pub key_type: Option<Type>,
+    /// Only list the keys allowing at least all of these usages.
+    pub usage_flags: Option<UsageFlags>,
+    /// Only list the keys whose name starts with this prefix.
+    pub name_prefix: Option<String>,
+    /// Continuation token returned in the `next_page_token` field of a previous result, to
+    /// continue listing from where that result stopped.
+    pub page_token: Option<String>,
+    /// Maximum number of keys to return in the result.
+    pub limit: Option<u32>,
+}
+
+impl Operation {
+    /// Check if a key matches all the filters of the operation
+    ///
+    /// The pagination fields are not taken into account.
+    pub fn matches(&self, key: &KeyInfo) -> bool {
+        self.provider_id
+            .iter()
+            .all(|&provider_id| provider_id == key.provider_id)
+            && self
+                .key_type
+                .iter()
+                .all(|&key_type| key_type == key.attributes.key_type)
+            && self
+                .usage_flags
+                .iter()
+                .all(|&usage_flags| is_usage_subset(usage_flags, key.attributes.policy.usage_flags))
+            && self
+                .name_prefix
+                .as_ref()
+                .iter()
+                .all(|prefix| key.name.starts_with(prefix.as_str()))
+    }
+}
 
 /// Native object for key listing result.
 #[derive(Debug)]
 pub struct Result {
     /// A list of `KeyInfo` structures.
     pub keys: Vec<KeyInfo>,
+    /// Token to pass in the `page_token` field of the next operation to get the following keys.
+    /// `None` if there are no more keys to list.
+    pub next_page_token: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::Algorithm;
+    use crate::operations::psa_key_attributes::{Lifetime, Policy};
+
+    fn get_key_info() -> KeyInfo {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_encrypt().set_decrypt();
+        KeyInfo {
+            provider_id: ProviderId::MbedCrypto,
+            name: String::from("tenant-a/backup"),
+            attributes: Attributes {
+                lifetime: Lifetime::Persistent,
+                key_type: Type::Aes,
+                bits: 256,
+                policy: Policy {
+                    usage_flags,
+                    permitted_algorithms: Algorithm::None,
+                },
+            },
+            creation_time: None,
+            origin: None,
+        }
+    }
+
+    #[test]
+    fn default_matches_everything() {
+        assert!(Operation::default().matches(&get_key_info()));
+    }
+
+    #[test]
+    fn filters() {
+        let key = get_key_info();
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_encrypt();
+        let mut op = Operation {
+            provider_id: Some(ProviderId::MbedCrypto),
+            key_type: Some(Type::Aes),
+            usage_flags: Some(usage_flags),
+            name_prefix: Some(String::from("tenant-a/")),
+            ..Default::default()
+        };
+        assert!(op.matches(&key));
+
+        op.name_prefix = Some(String::from("tenant-b/"));
+        assert!(!op.matches(&key));
+        op.name_prefix = None;
+
+        op.provider_id = Some(ProviderId::Tpm);
+        assert!(!op.matches(&key));
+        op.provider_id = None;
+
+        op.key_type = Some(Type::Hmac);
+        assert!(!op.matches(&key));
+        op.key_type = None;
+
+        let _ = usage_flags.set_sign_hash();
+        op.usage_flags = Some(usage_flags);
+        assert!(!op.matches(&key));
+    }
 }
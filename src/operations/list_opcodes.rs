@@ -4,6 +4,7 @@
 //!
 //! List the opcodes supported by the provider.
 
+use super::psa_algorithm::Algorithm;
 use crate::requests::{Opcode, ProviderId};
 use std::collections::HashSet;
 
@@ -14,10 +15,104 @@ pub struct Operation {
     pub provider_id: ProviderId,
 }
 
+/// Constraints applied by the provider on the requests of an opcode.
+///
+/// Clients can use them to check requests locally before sending them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OpcodeInfo {
+    /// The opcode the constraints apply to.
+    pub opcode: Opcode,
+    /// Maximum size, in bytes, of the input data of a request, if limited.
+    pub max_input_size: Option<usize>,
+    /// Algorithms supported for this opcode. An empty list means that the provider does not
+    /// advertise its supported algorithms.
+    pub supported_algorithms: Vec<Algorithm>,
+}
+
+impl OpcodeInfo {
+    /// Check if the opcode needs admin rights
+    pub fn requires_admin(&self) -> bool {
+        self.opcode.is_admin()
+    }
+
+    /// Check if an input of `size` bytes is within the limits of the provider
+    pub fn accepts_input_size(&self, size: usize) -> bool {
+        self.max_input_size
+            .iter()
+            .all(|&max_input_size| size <= max_input_size)
+    }
+
+    /// Check if the algorithm might be supported by the provider
+    ///
+    /// Returns `true` if the provider does not advertise its supported algorithms.
+    pub fn supports_alg(&self, alg: Algorithm) -> bool {
+        self.supported_algorithms.is_empty() || self.supported_algorithms.contains(&alg)
+    }
+}
+
 /// Native object for opcode listing result.
 #[derive(Debug)]
 pub struct Result {
     /// `opcodes` holds a list of opcodes supported by the provider identified in
     /// the request.
     pub opcodes: HashSet<Opcode>,
+    /// Constraints of the opcodes for which the provider advertises them.
+    pub opcode_info: Vec<OpcodeInfo>,
+}
+
+impl Result {
+    /// Get the constraints advertised for an opcode, if any
+    pub fn info(&self, opcode: Opcode) -> Option<&OpcodeInfo> {
+        self.opcode_info.iter().find(|info| info.opcode == opcode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{Cipher, Hash};
+
+    fn get_result() -> Result {
+        Result {
+            opcodes: [
+                Opcode::PsaHashCompute,
+                Opcode::PsaCipherEncrypt,
+                Opcode::DeleteClient,
+            ]
+            .iter()
+            .copied()
+            .collect(),
+            opcode_info: vec![
+                OpcodeInfo {
+                    opcode: Opcode::PsaHashCompute,
+                    max_input_size: Some(1024),
+                    supported_algorithms: vec![Hash::Sha256.into()],
+                },
+                OpcodeInfo {
+                    opcode: Opcode::DeleteClient,
+                    max_input_size: None,
+                    supported_algorithms: Vec::new(),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn opcode_info_checks() {
+        let result = get_result();
+
+        let hash_info = result.info(Opcode::PsaHashCompute).unwrap();
+        assert!(!hash_info.requires_admin());
+        assert!(hash_info.accepts_input_size(1024));
+        assert!(!hash_info.accepts_input_size(1025));
+        assert!(hash_info.supports_alg(Hash::Sha256.into()));
+        assert!(!hash_info.supports_alg(Hash::Sha512.into()));
+
+        let delete_info = result.info(Opcode::DeleteClient).unwrap();
+        assert!(delete_info.requires_admin());
+        assert!(delete_info.accepts_input_size(usize::MAX));
+        assert!(delete_info.supports_alg(Cipher::Ctr.into()));
+
+        assert!(result.info(Opcode::PsaCipherEncrypt).is_none());
+    }
 }
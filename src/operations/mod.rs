@@ -33,11 +33,43 @@ pub mod delete_client;
 pub mod list_clients;
 pub mod psa_generate_random;
 pub mod psa_raw_key_agreement;
+pub mod psa_cipher_encrypt;
+pub mod psa_cipher_decrypt;
+pub mod psa_mac_compute;
+pub mod psa_mac_verify;
+pub mod psa_key_derivation;
+pub mod psa_key_agreement;
+pub mod can_do_crypto;
+pub mod prepare_key_attestation;
+pub mod attest_key;
+pub mod psa_hash_setup;
+pub mod psa_hash_update;
+pub mod psa_hash_finish;
+pub mod psa_hash_abort;
+pub mod psa_aead_encrypt_setup;
+pub mod psa_aead_decrypt_setup;
+pub mod psa_aead_update_ad;
+pub mod psa_aead_update;
+pub mod psa_aead_finish;
+pub mod psa_aead_verify;
+pub mod psa_aead_abort;
+pub mod psa_copy_key;
+pub mod psa_get_key_attributes;
+pub mod batch;
+pub mod attach_certificate;
+pub mod get_certificate;
+pub mod list_certificates;
+pub mod remove_certificate;
+pub mod generate_csr;
+pub mod rotate_key;
 
 pub use psa_crypto::types::algorithm as psa_algorithm;
 pub use psa_crypto::types::key as psa_key_attributes;
 
-use crate::requests::{request::RequestBody, response::ResponseBody, BodyType, Opcode, Result};
+use crate::requests::{
+    request::RequestBody, response::ResponseBody, BodyType, Opcode, ResponseStatus, Result,
+};
+use log::error;
 
 /// Container type for operation conversion values, holding a native operation object
 /// to be passed in/out of a converter.
@@ -91,6 +123,64 @@ pub enum NativeOperation {
     PsaSignMessage(psa_sign_message::Operation),
     /// PsaVerifyMessage operation
     PsaVerifyMessage(psa_verify_message::Operation),
+    /// PsaCipherEncrypt operation
+    PsaCipherEncrypt(psa_cipher_encrypt::Operation),
+    /// PsaCipherDecrypt operation
+    PsaCipherDecrypt(psa_cipher_decrypt::Operation),
+    /// PsaMacCompute operation
+    PsaMacCompute(psa_mac_compute::Operation),
+    /// PsaMacVerify operation
+    PsaMacVerify(psa_mac_verify::Operation),
+    /// PsaKeyDerivation operation
+    PsaKeyDerivation(psa_key_derivation::Operation),
+    /// PsaKeyAgreement operation
+    PsaKeyAgreement(psa_key_agreement::Operation),
+    /// CanDoCrypto operation
+    CanDoCrypto(can_do_crypto::Operation),
+    /// PrepareKeyAttestation operation
+    PrepareKeyAttestation(prepare_key_attestation::Operation),
+    /// AttestKey operation
+    AttestKey(attest_key::Operation),
+    /// PsaHashSetup operation
+    PsaHashSetup(psa_hash_setup::Operation),
+    /// PsaHashUpdate operation
+    PsaHashUpdate(psa_hash_update::Operation),
+    /// PsaHashFinish operation
+    PsaHashFinish(psa_hash_finish::Operation),
+    /// PsaHashAbort operation
+    PsaHashAbort(psa_hash_abort::Operation),
+    /// PsaAeadEncryptSetup operation
+    PsaAeadEncryptSetup(psa_aead_encrypt_setup::Operation),
+    /// PsaAeadDecryptSetup operation
+    PsaAeadDecryptSetup(psa_aead_decrypt_setup::Operation),
+    /// PsaAeadUpdateAd operation
+    PsaAeadUpdateAd(psa_aead_update_ad::Operation),
+    /// PsaAeadUpdate operation
+    PsaAeadUpdate(psa_aead_update::Operation),
+    /// PsaAeadFinish operation
+    PsaAeadFinish(psa_aead_finish::Operation),
+    /// PsaAeadVerify operation
+    PsaAeadVerify(psa_aead_verify::Operation),
+    /// PsaAeadAbort operation
+    PsaAeadAbort(psa_aead_abort::Operation),
+    /// PsaCopyKey operation
+    PsaCopyKey(psa_copy_key::Operation),
+    /// PsaGetKeyAttributes operation
+    PsaGetKeyAttributes(psa_get_key_attributes::Operation),
+    /// Batch operation
+    Batch(batch::Operation),
+    /// AttachCertificate operation
+    AttachCertificate(attach_certificate::Operation),
+    /// GetCertificate operation
+    GetCertificate(get_certificate::Operation),
+    /// ListCertificates operation
+    ListCertificates(list_certificates::Operation),
+    /// RemoveCertificate operation
+    RemoveCertificate(remove_certificate::Operation),
+    /// GenerateCsr operation
+    GenerateCsr(generate_csr::Operation),
+    /// RotateKey operation
+    RotateKey(rotate_key::Operation),
 }
 
 impl NativeOperation {
@@ -121,6 +211,35 @@ impl NativeOperation {
             NativeOperation::PsaRawKeyAgreement(_) => Opcode::PsaRawKeyAgreement,
             NativeOperation::PsaSignMessage(_) => Opcode::PsaSignMessage,
             NativeOperation::PsaVerifyMessage(_) => Opcode::PsaVerifyMessage,
+            NativeOperation::PsaCipherEncrypt(_) => Opcode::PsaCipherEncrypt,
+            NativeOperation::PsaCipherDecrypt(_) => Opcode::PsaCipherDecrypt,
+            NativeOperation::PsaMacCompute(_) => Opcode::PsaMacCompute,
+            NativeOperation::PsaMacVerify(_) => Opcode::PsaMacVerify,
+            NativeOperation::PsaKeyDerivation(_) => Opcode::PsaKeyDerivation,
+            NativeOperation::PsaKeyAgreement(_) => Opcode::PsaKeyAgreement,
+            NativeOperation::CanDoCrypto(_) => Opcode::CanDoCrypto,
+            NativeOperation::PrepareKeyAttestation(_) => Opcode::PrepareKeyAttestation,
+            NativeOperation::AttestKey(_) => Opcode::AttestKey,
+            NativeOperation::PsaHashSetup(_) => Opcode::PsaHashSetup,
+            NativeOperation::PsaHashUpdate(_) => Opcode::PsaHashUpdate,
+            NativeOperation::PsaHashFinish(_) => Opcode::PsaHashFinish,
+            NativeOperation::PsaHashAbort(_) => Opcode::PsaHashAbort,
+            NativeOperation::PsaAeadEncryptSetup(_) => Opcode::PsaAeadEncryptSetup,
+            NativeOperation::PsaAeadDecryptSetup(_) => Opcode::PsaAeadDecryptSetup,
+            NativeOperation::PsaAeadUpdateAd(_) => Opcode::PsaAeadUpdateAd,
+            NativeOperation::PsaAeadUpdate(_) => Opcode::PsaAeadUpdate,
+            NativeOperation::PsaAeadFinish(_) => Opcode::PsaAeadFinish,
+            NativeOperation::PsaAeadVerify(_) => Opcode::PsaAeadVerify,
+            NativeOperation::PsaAeadAbort(_) => Opcode::PsaAeadAbort,
+            NativeOperation::PsaCopyKey(_) => Opcode::PsaCopyKey,
+            NativeOperation::PsaGetKeyAttributes(_) => Opcode::PsaGetKeyAttributes,
+            NativeOperation::Batch(_) => Opcode::Batch,
+            NativeOperation::AttachCertificate(_) => Opcode::AttachCertificate,
+            NativeOperation::GetCertificate(_) => Opcode::GetCertificate,
+            NativeOperation::ListCertificates(_) => Opcode::ListCertificates,
+            NativeOperation::RemoveCertificate(_) => Opcode::RemoveCertificate,
+            NativeOperation::GenerateCsr(_) => Opcode::GenerateCsr,
+            NativeOperation::RotateKey(_) => Opcode::RotateKey,
         }
     }
 }
@@ -177,6 +296,64 @@ pub enum NativeResult {
     PsaSignMessage(psa_sign_message::Result),
     /// PsaVerifyMessage result
     PsaVerifyMessage(psa_verify_message::Result),
+    /// PsaCipherEncrypt result
+    PsaCipherEncrypt(psa_cipher_encrypt::Result),
+    /// PsaCipherDecrypt result
+    PsaCipherDecrypt(psa_cipher_decrypt::Result),
+    /// PsaMacCompute result
+    PsaMacCompute(psa_mac_compute::Result),
+    /// PsaMacVerify result
+    PsaMacVerify(psa_mac_verify::Result),
+    /// PsaKeyDerivation result
+    PsaKeyDerivation(psa_key_derivation::Result),
+    /// PsaKeyAgreement result
+    PsaKeyAgreement(psa_key_agreement::Result),
+    /// CanDoCrypto result
+    CanDoCrypto(can_do_crypto::Result),
+    /// PrepareKeyAttestation result
+    PrepareKeyAttestation(prepare_key_attestation::Result),
+    /// AttestKey result
+    AttestKey(attest_key::Result),
+    /// PsaHashSetup result
+    PsaHashSetup(psa_hash_setup::Result),
+    /// PsaHashUpdate result
+    PsaHashUpdate(psa_hash_update::Result),
+    /// PsaHashFinish result
+    PsaHashFinish(psa_hash_finish::Result),
+    /// PsaHashAbort result
+    PsaHashAbort(psa_hash_abort::Result),
+    /// PsaAeadEncryptSetup result
+    PsaAeadEncryptSetup(psa_aead_encrypt_setup::Result),
+    /// PsaAeadDecryptSetup result
+    PsaAeadDecryptSetup(psa_aead_decrypt_setup::Result),
+    /// PsaAeadUpdateAd result
+    PsaAeadUpdateAd(psa_aead_update_ad::Result),
+    /// PsaAeadUpdate result
+    PsaAeadUpdate(psa_aead_update::Result),
+    /// PsaAeadFinish result
+    PsaAeadFinish(psa_aead_finish::Result),
+    /// PsaAeadVerify result
+    PsaAeadVerify(psa_aead_verify::Result),
+    /// PsaAeadAbort result
+    PsaAeadAbort(psa_aead_abort::Result),
+    /// PsaCopyKey result
+    PsaCopyKey(psa_copy_key::Result),
+    /// PsaGetKeyAttributes result
+    PsaGetKeyAttributes(psa_get_key_attributes::Result),
+    /// Batch result
+    Batch(batch::Result),
+    /// AttachCertificate result
+    AttachCertificate(attach_certificate::Result),
+    /// GetCertificate result
+    GetCertificate(get_certificate::Result),
+    /// ListCertificates result
+    ListCertificates(list_certificates::Result),
+    /// RemoveCertificate result
+    RemoveCertificate(remove_certificate::Result),
+    /// GenerateCsr result
+    GenerateCsr(generate_csr::Result),
+    /// RotateKey result
+    RotateKey(rotate_key::Result),
 }
 
 impl NativeResult {
@@ -207,6 +384,35 @@ impl NativeResult {
             NativeResult::PsaRawKeyAgreement(_) => Opcode::PsaRawKeyAgreement,
             NativeResult::PsaSignMessage(_) => Opcode::PsaSignMessage,
             NativeResult::PsaVerifyMessage(_) => Opcode::PsaVerifyMessage,
+            NativeResult::PsaCipherEncrypt(_) => Opcode::PsaCipherEncrypt,
+            NativeResult::PsaCipherDecrypt(_) => Opcode::PsaCipherDecrypt,
+            NativeResult::PsaMacCompute(_) => Opcode::PsaMacCompute,
+            NativeResult::PsaMacVerify(_) => Opcode::PsaMacVerify,
+            NativeResult::PsaKeyDerivation(_) => Opcode::PsaKeyDerivation,
+            NativeResult::PsaKeyAgreement(_) => Opcode::PsaKeyAgreement,
+            NativeResult::CanDoCrypto(_) => Opcode::CanDoCrypto,
+            NativeResult::PrepareKeyAttestation(_) => Opcode::PrepareKeyAttestation,
+            NativeResult::AttestKey(_) => Opcode::AttestKey,
+            NativeResult::PsaHashSetup(_) => Opcode::PsaHashSetup,
+            NativeResult::PsaHashUpdate(_) => Opcode::PsaHashUpdate,
+            NativeResult::PsaHashFinish(_) => Opcode::PsaHashFinish,
+            NativeResult::PsaHashAbort(_) => Opcode::PsaHashAbort,
+            NativeResult::PsaAeadEncryptSetup(_) => Opcode::PsaAeadEncryptSetup,
+            NativeResult::PsaAeadDecryptSetup(_) => Opcode::PsaAeadDecryptSetup,
+            NativeResult::PsaAeadUpdateAd(_) => Opcode::PsaAeadUpdateAd,
+            NativeResult::PsaAeadUpdate(_) => Opcode::PsaAeadUpdate,
+            NativeResult::PsaAeadFinish(_) => Opcode::PsaAeadFinish,
+            NativeResult::PsaAeadVerify(_) => Opcode::PsaAeadVerify,
+            NativeResult::PsaAeadAbort(_) => Opcode::PsaAeadAbort,
+            NativeResult::PsaCopyKey(_) => Opcode::PsaCopyKey,
+            NativeResult::PsaGetKeyAttributes(_) => Opcode::PsaGetKeyAttributes,
+            NativeResult::Batch(_) => Opcode::Batch,
+            NativeResult::AttachCertificate(_) => Opcode::AttachCertificate,
+            NativeResult::GetCertificate(_) => Opcode::GetCertificate,
+            NativeResult::ListCertificates(_) => Opcode::ListCertificates,
+            NativeResult::RemoveCertificate(_) => Opcode::RemoveCertificate,
+            NativeResult::GenerateCsr(_) => Opcode::GenerateCsr,
+            NativeResult::RotateKey(_) => Opcode::RotateKey,
         }
     }
 }
@@ -383,6 +589,180 @@ impl From<psa_verify_message::Operation> for NativeOperation {
     }
 }
 
+impl From<psa_cipher_encrypt::Operation> for NativeOperation {
+    fn from(op: psa_cipher_encrypt::Operation) -> Self {
+        NativeOperation::PsaCipherEncrypt(op)
+    }
+}
+
+impl From<psa_cipher_decrypt::Operation> for NativeOperation {
+    fn from(op: psa_cipher_decrypt::Operation) -> Self {
+        NativeOperation::PsaCipherDecrypt(op)
+    }
+}
+
+impl From<psa_mac_compute::Operation> for NativeOperation {
+    fn from(op: psa_mac_compute::Operation) -> Self {
+        NativeOperation::PsaMacCompute(op)
+    }
+}
+
+impl From<psa_mac_verify::Operation> for NativeOperation {
+    fn from(op: psa_mac_verify::Operation) -> Self {
+        NativeOperation::PsaMacVerify(op)
+    }
+}
+
+impl From<psa_key_derivation::Operation> for NativeOperation {
+    fn from(op: psa_key_derivation::Operation) -> Self {
+        NativeOperation::PsaKeyDerivation(op)
+    }
+}
+
+impl From<psa_key_agreement::Operation> for NativeOperation {
+    fn from(op: psa_key_agreement::Operation) -> Self {
+        NativeOperation::PsaKeyAgreement(op)
+    }
+}
+
+impl From<can_do_crypto::Operation> for NativeOperation {
+    fn from(op: can_do_crypto::Operation) -> Self {
+        NativeOperation::CanDoCrypto(op)
+    }
+}
+
+impl From<prepare_key_attestation::Operation> for NativeOperation {
+    fn from(op: prepare_key_attestation::Operation) -> Self {
+        NativeOperation::PrepareKeyAttestation(op)
+    }
+}
+
+impl From<attest_key::Operation> for NativeOperation {
+    fn from(op: attest_key::Operation) -> Self {
+        NativeOperation::AttestKey(op)
+    }
+}
+
+impl From<psa_hash_setup::Operation> for NativeOperation {
+    fn from(op: psa_hash_setup::Operation) -> Self {
+        NativeOperation::PsaHashSetup(op)
+    }
+}
+
+impl From<psa_hash_update::Operation> for NativeOperation {
+    fn from(op: psa_hash_update::Operation) -> Self {
+        NativeOperation::PsaHashUpdate(op)
+    }
+}
+
+impl From<psa_hash_finish::Operation> for NativeOperation {
+    fn from(op: psa_hash_finish::Operation) -> Self {
+        NativeOperation::PsaHashFinish(op)
+    }
+}
+
+impl From<psa_hash_abort::Operation> for NativeOperation {
+    fn from(op: psa_hash_abort::Operation) -> Self {
+        NativeOperation::PsaHashAbort(op)
+    }
+}
+
+impl From<psa_aead_encrypt_setup::Operation> for NativeOperation {
+    fn from(op: psa_aead_encrypt_setup::Operation) -> Self {
+        NativeOperation::PsaAeadEncryptSetup(op)
+    }
+}
+
+impl From<psa_aead_decrypt_setup::Operation> for NativeOperation {
+    fn from(op: psa_aead_decrypt_setup::Operation) -> Self {
+        NativeOperation::PsaAeadDecryptSetup(op)
+    }
+}
+
+impl From<psa_aead_update_ad::Operation> for NativeOperation {
+    fn from(op: psa_aead_update_ad::Operation) -> Self {
+        NativeOperation::PsaAeadUpdateAd(op)
+    }
+}
+
+impl From<psa_aead_update::Operation> for NativeOperation {
+    fn from(op: psa_aead_update::Operation) -> Self {
+        NativeOperation::PsaAeadUpdate(op)
+    }
+}
+
+impl From<psa_aead_finish::Operation> for NativeOperation {
+    fn from(op: psa_aead_finish::Operation) -> Self {
+        NativeOperation::PsaAeadFinish(op)
+    }
+}
+
+impl From<psa_aead_verify::Operation> for NativeOperation {
+    fn from(op: psa_aead_verify::Operation) -> Self {
+        NativeOperation::PsaAeadVerify(op)
+    }
+}
+
+impl From<psa_aead_abort::Operation> for NativeOperation {
+    fn from(op: psa_aead_abort::Operation) -> Self {
+        NativeOperation::PsaAeadAbort(op)
+    }
+}
+
+impl From<psa_copy_key::Operation> for NativeOperation {
+    fn from(op: psa_copy_key::Operation) -> Self {
+        NativeOperation::PsaCopyKey(op)
+    }
+}
+
+impl From<psa_get_key_attributes::Operation> for NativeOperation {
+    fn from(op: psa_get_key_attributes::Operation) -> Self {
+        NativeOperation::PsaGetKeyAttributes(op)
+    }
+}
+
+impl From<batch::Operation> for NativeOperation {
+    fn from(op: batch::Operation) -> Self {
+        NativeOperation::Batch(op)
+    }
+}
+
+impl From<attach_certificate::Operation> for NativeOperation {
+    fn from(op: attach_certificate::Operation) -> Self {
+        NativeOperation::AttachCertificate(op)
+    }
+}
+
+impl From<get_certificate::Operation> for NativeOperation {
+    fn from(op: get_certificate::Operation) -> Self {
+        NativeOperation::GetCertificate(op)
+    }
+}
+
+impl From<list_certificates::Operation> for NativeOperation {
+    fn from(op: list_certificates::Operation) -> Self {
+        NativeOperation::ListCertificates(op)
+    }
+}
+
+impl From<remove_certificate::Operation> for NativeOperation {
+    fn from(op: remove_certificate::Operation) -> Self {
+        NativeOperation::RemoveCertificate(op)
+    }
+}
+
+impl From<generate_csr::Operation> for NativeOperation {
+    fn from(op: generate_csr::Operation) -> Self {
+        NativeOperation::GenerateCsr(op)
+    }
+}
+
+impl From<rotate_key::Operation> for NativeOperation {
+    fn from(op: rotate_key::Operation) -> Self {
+        NativeOperation::RotateKey(op)
+    }
+}
+
 impl From<list_providers::Result> for NativeResult {
     fn from(op: list_providers::Result) -> Self {
         NativeResult::ListProviders(op)
@@ -526,3 +906,216 @@ impl From<psa_verify_message::Result> for NativeResult {
         NativeResult::PsaVerifyMessage(op)
     }
 }
+
+impl From<psa_cipher_encrypt::Result> for NativeResult {
+    fn from(op: psa_cipher_encrypt::Result) -> Self {
+        NativeResult::PsaCipherEncrypt(op)
+    }
+}
+
+impl From<psa_cipher_decrypt::Result> for NativeResult {
+    fn from(op: psa_cipher_decrypt::Result) -> Self {
+        NativeResult::PsaCipherDecrypt(op)
+    }
+}
+
+impl From<psa_mac_compute::Result> for NativeResult {
+    fn from(op: psa_mac_compute::Result) -> Self {
+        NativeResult::PsaMacCompute(op)
+    }
+}
+
+impl From<psa_mac_verify::Result> for NativeResult {
+    fn from(op: psa_mac_verify::Result) -> Self {
+        NativeResult::PsaMacVerify(op)
+    }
+}
+
+impl From<psa_key_derivation::Result> for NativeResult {
+    fn from(op: psa_key_derivation::Result) -> Self {
+        NativeResult::PsaKeyDerivation(op)
+    }
+}
+
+impl From<psa_key_agreement::Result> for NativeResult {
+    fn from(op: psa_key_agreement::Result) -> Self {
+        NativeResult::PsaKeyAgreement(op)
+    }
+}
+
+impl From<can_do_crypto::Result> for NativeResult {
+    fn from(op: can_do_crypto::Result) -> Self {
+        NativeResult::CanDoCrypto(op)
+    }
+}
+
+impl From<prepare_key_attestation::Result> for NativeResult {
+    fn from(op: prepare_key_attestation::Result) -> Self {
+        NativeResult::PrepareKeyAttestation(op)
+    }
+}
+
+impl From<attest_key::Result> for NativeResult {
+    fn from(op: attest_key::Result) -> Self {
+        NativeResult::AttestKey(op)
+    }
+}
+
+impl From<psa_hash_setup::Result> for NativeResult {
+    fn from(op: psa_hash_setup::Result) -> Self {
+        NativeResult::PsaHashSetup(op)
+    }
+}
+
+impl From<psa_hash_update::Result> for NativeResult {
+    fn from(op: psa_hash_update::Result) -> Self {
+        NativeResult::PsaHashUpdate(op)
+    }
+}
+
+impl From<psa_hash_finish::Result> for NativeResult {
+    fn from(op: psa_hash_finish::Result) -> Self {
+        NativeResult::PsaHashFinish(op)
+    }
+}
+
+impl From<psa_hash_abort::Result> for NativeResult {
+    fn from(op: psa_hash_abort::Result) -> Self {
+        NativeResult::PsaHashAbort(op)
+    }
+}
+
+impl From<psa_aead_encrypt_setup::Result> for NativeResult {
+    fn from(op: psa_aead_encrypt_setup::Result) -> Self {
+        NativeResult::PsaAeadEncryptSetup(op)
+    }
+}
+
+impl From<psa_aead_decrypt_setup::Result> for NativeResult {
+    fn from(op: psa_aead_decrypt_setup::Result) -> Self {
+        NativeResult::PsaAeadDecryptSetup(op)
+    }
+}
+
+impl From<psa_aead_update_ad::Result> for NativeResult {
+    fn from(op: psa_aead_update_ad::Result) -> Self {
+        NativeResult::PsaAeadUpdateAd(op)
+    }
+}
+
+impl From<psa_aead_update::Result> for NativeResult {
+    fn from(op: psa_aead_update::Result) -> Self {
+        NativeResult::PsaAeadUpdate(op)
+    }
+}
+
+impl From<psa_aead_finish::Result> for NativeResult {
+    fn from(op: psa_aead_finish::Result) -> Self {
+        NativeResult::PsaAeadFinish(op)
+    }
+}
+
+impl From<psa_aead_verify::Result> for NativeResult {
+    fn from(op: psa_aead_verify::Result) -> Self {
+        NativeResult::PsaAeadVerify(op)
+    }
+}
+
+impl From<psa_aead_abort::Result> for NativeResult {
+    fn from(op: psa_aead_abort::Result) -> Self {
+        NativeResult::PsaAeadAbort(op)
+    }
+}
+
+impl From<psa_copy_key::Result> for NativeResult {
+    fn from(op: psa_copy_key::Result) -> Self {
+        NativeResult::PsaCopyKey(op)
+    }
+}
+
+impl From<psa_get_key_attributes::Result> for NativeResult {
+    fn from(op: psa_get_key_attributes::Result) -> Self {
+        NativeResult::PsaGetKeyAttributes(op)
+    }
+}
+
+impl From<batch::Result> for NativeResult {
+    fn from(op: batch::Result) -> Self {
+        NativeResult::Batch(op)
+    }
+}
+
+impl From<attach_certificate::Result> for NativeResult {
+    fn from(op: attach_certificate::Result) -> Self {
+        NativeResult::AttachCertificate(op)
+    }
+}
+
+impl From<get_certificate::Result> for NativeResult {
+    fn from(op: get_certificate::Result) -> Self {
+        NativeResult::GetCertificate(op)
+    }
+}
+
+impl From<list_certificates::Result> for NativeResult {
+    fn from(op: list_certificates::Result) -> Self {
+        NativeResult::ListCertificates(op)
+    }
+}
+
+impl From<remove_certificate::Result> for NativeResult {
+    fn from(op: remove_certificate::Result) -> Self {
+        NativeResult::RemoveCertificate(op)
+    }
+}
+
+impl From<generate_csr::Result> for NativeResult {
+    fn from(op: generate_csr::Result) -> Self {
+        NativeResult::GenerateCsr(op)
+    }
+}
+
+impl From<rotate_key::Result> for NativeResult {
+    fn from(op: rotate_key::Result) -> Self {
+        NativeResult::RotateKey(op)
+    }
+}
+
+/// Check if all the usages allowed by `usage_flags` are also allowed by `allowed`.
+fn is_usage_subset(
+    usage_flags: psa_key_attributes::UsageFlags,
+    allowed: psa_key_attributes::UsageFlags,
+) -> bool {
+    let flags = [
+        (usage_flags.export(), allowed.export()),
+        (usage_flags.copy(), allowed.copy()),
+        (usage_flags.cache(), allowed.cache()),
+        (usage_flags.encrypt(), allowed.encrypt()),
+        (usage_flags.decrypt(), allowed.decrypt()),
+        (usage_flags.sign_message(), allowed.sign_message()),
+        (usage_flags.verify_message(), allowed.verify_message()),
+        (usage_flags.sign_hash(), allowed.sign_hash()),
+        (usage_flags.verify_hash(), allowed.verify_hash()),
+        (usage_flags.derive(), allowed.derive()),
+    ];
+    flags.iter().all(|&(flag, allowed)| !flag || allowed)
+}
+
+/// Separator between the name of a key and its version number (see [`rotate_key`]).
+pub const VERSION_SEPARATOR: char = '@';
+
+/// Check that a name can be given to a new key.
+///
+/// Names containing [`VERSION_SEPARATOR`] are reserved for key versions, so that a new key can not
+/// be confused with the version of another one. All the operations creating keys check the name
+/// of the new key with this function in their `validate` method.
+fn check_key_name(key_name: &str) -> Result<()> {
+    if key_name.contains(VERSION_SEPARATOR) {
+        error!(
+            "Key names containing \"{}\" are reserved for key versions.",
+            VERSION_SEPARATOR
+        );
+        return Err(ResponseStatus::PsaErrorInvalidArgument);
+    }
+    Ok(())
+}
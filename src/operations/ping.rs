@@ -2,8 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 //! # Ping operation
 //!
-//! The Ping operation is used to check if the service is alive and determine the highest wire
-//! protocol version a client can use.
+//! The Ping operation is used to check if the service is alive and determine the wire protocol
+//! versions and body types a client can use.
+pub use crate::requests::common::WireProtocolVersion;
+use crate::requests::BodyType;
 
 /// Native object for Ping operation.
 #[derive(Copy, Clone, Debug)]
@@ -13,10 +15,81 @@ pub struct Operation;
 ///
 /// The latest wire protocol version supported by the service. The version is represented as `x.y`
 /// where `x` is the version major and `y` the version minor.
-#[derive(Copy, Clone, Debug)]
+///
+/// Services can also advertise all the wire protocol versions and body types they support, as
+/// well as the maximum size of request body they accept.
+#[derive(Clone, Debug, PartialEq)]
 pub struct Result {
     /// Supported latest wire protocol version major
     pub wire_protocol_version_maj: u8,
     /// Supported latest wire protocol version minor
     pub wire_protocol_version_min: u8,
+    /// All the wire protocol versions supported by the service. Services that do not advertise
+    /// them are assumed to only support the latest version.
+    pub supported_wire_protocol_versions: Vec<WireProtocolVersion>,
+    /// Body types supported by the service. Empty if the service does not advertise them.
+    pub supported_body_types: Vec<BodyType>,
+    /// Maximum size of request body, in bytes, accepted by the service, if advertised. Must not
+    /// be zero.
+    pub max_body_size: Option<usize>,
+}
+
+impl Result {
+    /// Get the highest wire protocol version supported by both the service and the client
+    ///
+    /// Returns `None` if no version in `client_versions` is supported by the service.
+    pub fn highest_common_version(
+        &self,
+        client_versions: &[WireProtocolVersion],
+    ) -> Option<WireProtocolVersion> {
+        let latest = WireProtocolVersion {
+            major: self.wire_protocol_version_maj,
+            minor: self.wire_protocol_version_min,
+        };
+        client_versions
+            .iter()
+            .filter(|version| {
+                **version == latest || self.supported_wire_protocol_versions.contains(version)
+            })
+            .max()
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const V1_0: WireProtocolVersion = WireProtocolVersion { major: 1, minor: 0 };
+    const V1_1: WireProtocolVersion = WireProtocolVersion { major: 1, minor: 1 };
+    const V2_0: WireProtocolVersion = WireProtocolVersion { major: 2, minor: 0 };
+
+    #[test]
+    fn highest_common_version() {
+        let result = Result {
+            wire_protocol_version_maj: 1,
+            wire_protocol_version_min: 1,
+            supported_wire_protocol_versions: vec![V1_0, V1_1],
+            supported_body_types: vec![BodyType::Protobuf],
+            max_body_size: None,
+        };
+
+        assert_eq!(result.highest_common_version(&[V1_0, V1_1]), Some(V1_1));
+        assert_eq!(result.highest_common_version(&[V2_0, V1_0]), Some(V1_0));
+        assert_eq!(result.highest_common_version(&[V2_0]), None);
+    }
+
+    #[test]
+    fn highest_common_version_not_advertised() {
+        let result = Result {
+            wire_protocol_version_maj: 1,
+            wire_protocol_version_min: 0,
+            supported_wire_protocol_versions: Vec::new(),
+            supported_body_types: Vec::new(),
+            max_body_size: None,
+        };
+
+        assert_eq!(result.highest_common_version(&[V1_0, V1_1]), Some(V1_0));
+        assert_eq!(result.highest_common_version(&[V1_1]), None);
+    }
 }
@@ -0,0 +1,19 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaAeadAbort operation
+//!
+//! Abort a multipart AEAD operation and release its resources.
+
+/// Native object for AEAD abort operations.
+#[derive(Copy, Clone, Debug)]
+pub struct Operation {
+    /// Handle of the multipart operation, as returned by `PsaAeadEncryptSetup` or
+    /// `PsaAeadDecryptSetup`. The handle is no longer valid once this operation completes.
+    pub operation_handle: u32,
+}
+
+/// Native object for AEAD abort result.
+///
+/// The true result is returned in the `status` field of the response.
+#[derive(Copy, Clone, Debug)]
+pub struct Result;
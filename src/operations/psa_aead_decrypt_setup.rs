@@ -0,0 +1,111 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaAeadDecryptSetup operation
+//!
+//! Start a multipart authenticated decryption operation.
+
+use super::psa_aead_encrypt_setup::{requires_lengths, Lengths};
+use super::psa_key_attributes::Attributes;
+use crate::operations::psa_algorithm::Aead;
+use crate::requests::ResponseStatus;
+use derivative::Derivative;
+
+/// Native object for AEAD decryption setup operations.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Operation {
+    /// Defines which key should be used for the decryption operation.
+    pub key_name: String,
+    /// An AEAD decryption algorithm that is compatible with the key type.
+    pub alg: Aead,
+    /// Nonce or IV to use.
+    #[derivative(Debug = "ignore")]
+    pub nonce: zeroize::Zeroizing<Vec<u8>>,
+    /// Total lengths of the inputs. Mandatory for algorithms that need to know them in advance,
+    /// such as CCM.
+    pub lengths: Option<Lengths>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the key policy allows decrypting messages
+    /// * the key policy allows the decryption algorithm requested in the operation
+    /// * the key type is compatible with the requested algorithm
+    /// * the nonce is valid (not length 0)
+    /// * the lengths are given if the algorithm requires them
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        key_attributes.can_decrypt_message()?;
+        key_attributes.permits_alg(self.alg.into())?;
+        key_attributes.compatible_with_alg(self.alg.into())?;
+        if self.nonce.is_empty() || (requires_lengths(self.alg) && self.lengths.is_none()) {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        Ok(())
+    }
+}
+
+/// Native object for AEAD decryption setup result.
+#[derive(Copy, Clone, Debug)]
+pub struct Result {
+    /// Handle identifying the multipart operation in the service. It must be passed to the
+    /// `PsaAeadUpdateAd`, `PsaAeadUpdate`, `PsaAeadVerify` and `PsaAeadAbort` operations.
+    pub operation_handle: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::AeadWithDefaultLengthTag;
+    use crate::operations::psa_key_attributes::{Lifetime, Policy, Type, UsageFlags};
+
+    fn get_attrs() -> Attributes {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_decrypt();
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::Aes,
+            bits: 0,
+            policy: Policy {
+                usage_flags,
+                permitted_algorithms: Aead::AeadWithDefaultLengthTag(AeadWithDefaultLengthTag::Gcm)
+                    .into(),
+            },
+        }
+    }
+
+    fn get_op() -> Operation {
+        Operation {
+            key_name: String::from("some key"),
+            alg: AeadWithDefaultLengthTag::Gcm.into(),
+            nonce: vec![0xff; 12].into(),
+            lengths: None,
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op().validate(get_attrs()).unwrap();
+    }
+
+    #[test]
+    fn cannot_decrypt() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags = UsageFlags::default();
+        assert_eq!(
+            get_op().validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn invalid_nonce() {
+        let mut op = get_op();
+        op.nonce = vec![].into();
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
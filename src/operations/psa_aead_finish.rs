@@ -0,0 +1,27 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaAeadFinish operation
+//!
+//! Finish a multipart authenticated encryption operation.
+
+use derivative::Derivative;
+
+/// Native object for AEAD encryption finish operations.
+#[derive(Copy, Clone, Debug)]
+pub struct Operation {
+    /// Handle of the multipart operation, as returned by `PsaAeadEncryptSetup`. The handle is
+    /// no longer valid once this operation completes.
+    pub operation_handle: u32,
+}
+
+/// Native object for AEAD encryption finish result.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Result {
+    /// Last part of the ciphertext, containing any data buffered by the algorithm.
+    #[derivative(Debug = "ignore")]
+    pub ciphertext: zeroize::Zeroizing<Vec<u8>>,
+    /// Authentication tag of the whole message.
+    #[derivative(Debug = "ignore")]
+    pub tag: zeroize::Zeroizing<Vec<u8>>,
+}
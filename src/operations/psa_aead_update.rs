@@ -0,0 +1,30 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaAeadUpdate operation
+//!
+//! Encrypt or decrypt a chunk of the message in a multipart AEAD operation.
+
+use derivative::Derivative;
+
+/// Native object for AEAD update operations.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Operation {
+    /// Handle of the multipart operation, as returned by `PsaAeadEncryptSetup` or
+    /// `PsaAeadDecryptSetup`. All the additional data must have been passed before the first
+    /// update.
+    pub operation_handle: u32,
+    /// The next chunk of the message to encrypt or decrypt.
+    #[derivative(Debug = "ignore")]
+    pub input: zeroize::Zeroizing<Vec<u8>>,
+}
+
+/// Native object for AEAD update result.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Result {
+    /// Output produced for this chunk. Its size might differ from the size of the input as the
+    /// algorithm can buffer data.
+    #[derivative(Debug = "ignore")]
+    pub output: zeroize::Zeroizing<Vec<u8>>,
+}
@@ -0,0 +1,25 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaAeadUpdateAd operation
+//!
+//! Pass a chunk of additional data to a multipart AEAD operation.
+
+use derivative::Derivative;
+
+/// Native object for AEAD additional data update operations.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Operation {
+    /// Handle of the multipart operation, as returned by `PsaAeadEncryptSetup` or
+    /// `PsaAeadDecryptSetup`.
+    pub operation_handle: u32,
+    /// The next chunk of additional data that will be authenticated but not encrypted.
+    #[derivative(Debug = "ignore")]
+    pub additional_data: zeroize::Zeroizing<Vec<u8>>,
+}
+
+/// Native object for AEAD additional data update result.
+///
+/// The true result is returned in the `status` field of the response.
+#[derive(Copy, Clone, Debug)]
+pub struct Result;
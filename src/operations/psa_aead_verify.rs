@@ -0,0 +1,62 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaAeadVerify operation
+//!
+//! Finish a multipart authenticated decryption operation and check the authentication tag.
+
+use crate::requests::ResponseStatus;
+use derivative::Derivative;
+
+/// Native object for AEAD decryption verify operations.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Operation {
+    /// Handle of the multipart operation, as returned by `PsaAeadDecryptSetup`. The handle is
+    /// no longer valid once this operation completes.
+    pub operation_handle: u32,
+    /// Authentication tag to compare with the one computed over the whole message.
+    #[derivative(Debug = "ignore")]
+    pub tag: zeroize::Zeroizing<Vec<u8>>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation
+    ///
+    /// This method checks that the tag is valid (not length 0).
+    pub fn validate(&self) -> crate::requests::Result<()> {
+        if self.tag.is_empty() {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        Ok(())
+    }
+}
+
+/// Native object for AEAD decryption verify result.
+///
+/// If the tag does not match, the operation fails with `PsaErrorInvalidSignature` and no
+/// plaintext is returned.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Result {
+    /// Last part of the plaintext, containing any data buffered by the algorithm.
+    #[derivative(Debug = "ignore")]
+    pub plaintext: zeroize::Zeroizing<Vec<u8>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_tag() {
+        assert_eq!(
+            (Operation {
+                operation_handle: 1,
+                tag: vec![].into(),
+            })
+            .validate()
+            .unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
@@ -0,0 +1,165 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaCipherDecrypt operation
+//!
+//! Decrypt a short message with a symmetric cipher.
+
+use super::psa_cipher_encrypt::{is_block_aligned, iv_length};
+use super::psa_key_attributes::Attributes;
+use crate::operations::psa_algorithm::Cipher;
+use crate::requests::ResponseStatus;
+use derivative::Derivative;
+
+/// Native object for cipher decryption operations.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Operation {
+    /// Defines which key should be used for the decryption operation.
+    pub key_name: String,
+    /// A cipher decryption algorithm that is compatible with the key type.
+    pub alg: Cipher,
+    /// Data that will be decrypted. For algorithms that use an IV, the IV must be prepended to
+    /// the encrypted data.
+    #[derivative(Debug = "ignore")]
+    pub ciphertext: zeroize::Zeroizing<Vec<u8>>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the key policy allows decrypting messages
+    /// * the key policy allows the decryption algorithm requested in the operation
+    /// * the key type is compatible with the requested algorithm
+    /// * the ciphertext is longer than the IV expected by the algorithm
+    /// * the encrypted data is a multiple of the block size for block-based modes
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        key_attributes.can_decrypt_message()?;
+        key_attributes.permits_alg(self.alg.into())?;
+        key_attributes.compatible_with_alg(self.alg.into())?;
+        let iv_length = iv_length(key_attributes.key_type, self.alg);
+        if self.ciphertext.len() <= iv_length {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        if matches!(
+            self.alg,
+            Cipher::EcbNoPadding | Cipher::CbcNoPadding | Cipher::CbcPkcs7
+        ) && !is_block_aligned(self.ciphertext.len() - iv_length, key_attributes.key_type)
+        {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        Ok(())
+    }
+}
+
+/// Native object for cipher decrypt result.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Result {
+    /// Decrypted message.
+    #[derivative(Debug = "ignore")]
+    pub plaintext: zeroize::Zeroizing<Vec<u8>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_key_attributes::{Lifetime, Policy, Type, UsageFlags};
+
+    fn get_attrs() -> Attributes {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_decrypt();
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::Aes,
+            bits: 128,
+            policy: Policy {
+                usage_flags,
+                permitted_algorithms: Cipher::CbcPkcs7.into(),
+            },
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        (Operation {
+            key_name: String::from("some key"),
+            alg: Cipher::CbcPkcs7,
+            ciphertext: vec![0xff; 32].into(),
+        })
+        .validate(get_attrs())
+        .unwrap();
+    }
+
+    #[test]
+    fn cannot_decrypt() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags = UsageFlags::default();
+        assert_eq!(
+            (Operation {
+                key_name: String::from("some key"),
+                alg: Cipher::CbcPkcs7,
+                ciphertext: vec![0xff; 32].into(),
+            })
+            .validate(attrs)
+            .unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wrong_algorithm() {
+        assert_eq!(
+            (Operation {
+                key_name: String::from("some key"),
+                alg: Cipher::Xts,
+                ciphertext: vec![0xff; 32].into(),
+            })
+            .validate(get_attrs())
+            .unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn missing_iv() {
+        assert_eq!(
+            (Operation {
+                key_name: String::from("some key"),
+                alg: Cipher::CbcPkcs7,
+                ciphertext: vec![0xff; 16].into(),
+            })
+            .validate(get_attrs())
+            .unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn ciphertext_not_block_aligned() {
+        assert_eq!(
+            (Operation {
+                key_name: String::from("some key"),
+                alg: Cipher::CbcPkcs7,
+                ciphertext: vec![0xff; 31].into(),
+            })
+            .validate(get_attrs())
+            .unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn stream_cipher_iv() {
+        let mut attrs = get_attrs();
+        attrs.key_type = Type::Chacha20;
+        attrs.policy.permitted_algorithms = Cipher::StreamCipher.into();
+        (Operation {
+            key_name: String::from("some key"),
+            alg: Cipher::StreamCipher,
+            ciphertext: vec![0xff; 13].into(),
+        })
+        .validate(attrs)
+        .unwrap();
+    }
+}
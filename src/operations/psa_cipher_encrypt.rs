@@ -0,0 +1,189 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaCipherEncrypt operation
+//!
+//! Encrypt a short message with a symmetric cipher.
+
+use super::psa_key_attributes::{Attributes, Type};
+use crate::operations::psa_algorithm::Cipher;
+use crate::requests::ResponseStatus;
+use derivative::Derivative;
+
+/// Native object for cipher encryption operations.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Operation {
+    /// Defines which key should be used for the encryption operation.
+    pub key_name: String,
+    /// A cipher encryption algorithm that is compatible with the key type.
+    pub alg: Cipher,
+    /// Data that will be encrypted.
+    #[derivative(Debug = "ignore")]
+    pub plaintext: zeroize::Zeroizing<Vec<u8>>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the key policy allows encrypting messages
+    /// * the key policy allows the encryption algorithm requested in the operation
+    /// * the key type is compatible with the requested algorithm
+    /// * the message to encrypt is valid (not length 0)
+    /// * the message to encrypt is a multiple of the block size for modes without padding
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        key_attributes.can_encrypt_message()?;
+        key_attributes.permits_alg(self.alg.into())?;
+        key_attributes.compatible_with_alg(self.alg.into())?;
+        if self.plaintext.is_empty() {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        if matches!(self.alg, Cipher::EcbNoPadding | Cipher::CbcNoPadding)
+            && !is_block_aligned(self.plaintext.len(), key_attributes.key_type)
+        {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        Ok(())
+    }
+}
+
+/// Native object for cipher encrypt result.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Result {
+    /// The `ciphertext` field contains the encrypted data. For algorithms that use an IV, the
+    /// IV generated by the service is prepended to the encrypted data.
+    #[derivative(Debug = "ignore")]
+    pub ciphertext: zeroize::Zeroizing<Vec<u8>>,
+}
+
+/// Size in bytes of the blocks processed by the cipher of the given key type.
+///
+/// Stream ciphers are considered to have a block size of 1.
+pub(super) fn block_size(key_type: Type) -> usize {
+    match key_type {
+        Type::Aes | Type::Camellia => 16,
+        Type::Des => 8,
+        _ => 1,
+    }
+}
+
+/// Check if `len` is a multiple of the block size of the given key type.
+pub(super) fn is_block_aligned(len: usize, key_type: Type) -> bool {
+    len.checked_rem(block_size(key_type)) == Some(0)
+}
+
+/// Size in bytes of the IV prepended to the ciphertext for the given key type and algorithm.
+pub(super) fn iv_length(key_type: Type, alg: Cipher) -> usize {
+    match (key_type, alg) {
+        (_, Cipher::EcbNoPadding) => 0,
+        (Type::Chacha20, Cipher::StreamCipher) => 12,
+        (_, Cipher::StreamCipher) => 0,
+        (key_type, _) => block_size(key_type),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_key_attributes::{Lifetime, Policy, UsageFlags};
+
+    fn get_attrs() -> Attributes {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_encrypt();
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::Aes,
+            bits: 128,
+            policy: Policy {
+                usage_flags,
+                permitted_algorithms: Cipher::CbcNoPadding.into(),
+            },
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        (Operation {
+            key_name: String::from("some key"),
+            alg: Cipher::CbcNoPadding,
+            plaintext: vec![0xff; 32].into(),
+        })
+        .validate(get_attrs())
+        .unwrap();
+    }
+
+    #[test]
+    fn cannot_encrypt() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags = UsageFlags::default();
+        assert_eq!(
+            (Operation {
+                key_name: String::from("some key"),
+                alg: Cipher::CbcNoPadding,
+                plaintext: vec![0xff; 32].into(),
+            })
+            .validate(attrs)
+            .unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wrong_algorithm() {
+        assert_eq!(
+            (Operation {
+                key_name: String::from("some key"),
+                alg: Cipher::Ctr,
+                plaintext: vec![0xff; 32].into(),
+            })
+            .validate(get_attrs())
+            .unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wrong_key_type() {
+        let mut attrs = get_attrs();
+        attrs.key_type = Type::Hmac;
+        assert_eq!(
+            (Operation {
+                key_name: String::from("some key"),
+                alg: Cipher::CbcNoPadding,
+                plaintext: vec![0xff; 32].into(),
+            })
+            .validate(attrs)
+            .unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn invalid_plaintext() {
+        assert_eq!(
+            (Operation {
+                key_name: String::from("some key"),
+                alg: Cipher::CbcNoPadding,
+                plaintext: vec![].into(),
+            })
+            .validate(get_attrs())
+            .unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn plaintext_not_block_aligned() {
+        assert_eq!(
+            (Operation {
+                key_name: String::from("some key"),
+                alg: Cipher::CbcNoPadding,
+                plaintext: vec![0xff; 17].into(),
+            })
+            .validate(get_attrs())
+            .unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaCopyKey operation
+//!
+//! Copy a key under a new name, optionally restricting its policy.
+
+use super::check_key_name;
+use super::is_usage_subset;
+use super::psa_key_attributes::Attributes;
+use crate::operations::psa_algorithm::Algorithm;
+use crate::requests::ResponseStatus;
+
+/// Native object for copying a key.
+#[derive(Clone, Debug)]
+pub struct Operation {
+    /// `key_name` specifies the name of the key to copy.
+    pub key_name: String,
+    /// `target_key_name` specifies the name under which the copy will be stored.
+    pub target_key_name: String,
+    /// `attributes` specifies the attributes of the copy. The key type must be the same as the
+    /// one of the source key and the size must either match or be 0. The policy must be
+    /// equal to or more restrictive than the one of the source key.
+    pub attributes: Attributes,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the key policy allows copying the key
+    /// * the key type and size of the copy match the ones of the source key
+    /// * the usage flags of the copy are a subset of the ones of the source key
+    /// * the algorithm permitted for the copy is permitted by the source key
+    /// * the name of the copy is not reserved for key versions
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        if !key_attributes.policy.usage_flags.copy() {
+            return Err(ResponseStatus::PsaErrorNotPermitted);
+        }
+        if self.attributes.key_type != key_attributes.key_type
+            || (self.attributes.bits != 0 && self.attributes.bits != key_attributes.bits)
+        {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        if !is_usage_subset(
+            self.attributes.policy.usage_flags,
+            key_attributes.policy.usage_flags,
+        ) {
+            return Err(ResponseStatus::PsaErrorNotPermitted);
+        }
+        let permitted_algorithms = self.attributes.policy.permitted_algorithms;
+        if permitted_algorithms != Algorithm::None
+            && permitted_algorithms != key_attributes.policy.permitted_algorithms
+            && !key_attributes.is_alg_permitted(permitted_algorithms)
+        {
+            return Err(ResponseStatus::PsaErrorNotPermitted);
+        }
+        check_key_name(&self.target_key_name)
+    }
+}
+
+/// Native object for the result of copying a key.
+///
+/// The true result is returned in the `status` field of the response.
+#[derive(Copy, Clone, Debug)]
+pub struct Result;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{AsymmetricSignature, Hash, SignHash};
+    use crate::operations::psa_key_attributes::{EccFamily, Lifetime, Policy, Type, UsageFlags};
+
+    fn get_attrs() -> Attributes {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags
+            .set_copy()
+            .set_sign_hash()
+            .set_verify_hash()
+            .set_sign_message()
+            .set_verify_message();
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::EccKeyPair {
+                curve_family: EccFamily::SecpR1,
+            },
+            bits: 256,
+            policy: Policy {
+                usage_flags,
+                permitted_algorithms: AsymmetricSignature::Ecdsa {
+                    hash_alg: SignHash::Any,
+                }
+                .into(),
+            },
+        }
+    }
+
+    fn get_op() -> Operation {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_verify_hash().set_verify_message();
+        let mut attributes = get_attrs();
+        attributes.policy = Policy {
+            usage_flags,
+            permitted_algorithms: AsymmetricSignature::Ecdsa {
+                hash_alg: Hash::Sha256.into(),
+            }
+            .into(),
+        };
+        Operation {
+            key_name: String::from("source key"),
+            target_key_name: String::from("verify-only key"),
+            attributes,
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op().validate(get_attrs()).unwrap();
+
+        // The same policy as the source key is allowed, even with a wildcard algorithm.
+        let mut op = get_op();
+        op.attributes = get_attrs();
+        op.attributes.bits = 0;
+        op.validate(get_attrs()).unwrap();
+    }
+
+    #[test]
+    fn reserved_target_name() {
+        let mut op = get_op();
+        op.target_key_name = String::from("source key@2");
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn cannot_copy() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags = UsageFlags::default();
+        assert_eq!(
+            get_op().validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wrong_key_type_or_size() {
+        let mut op = get_op();
+        op.attributes.bits = 384;
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+
+        let mut op = get_op();
+        op.attributes.key_type = Type::EccPublicKey {
+            curve_family: EccFamily::SecpR1,
+        };
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn broader_usage_flags() {
+        let mut op = get_op();
+        let _ = op.attributes.policy.usage_flags.set_export();
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn different_algorithm() {
+        let mut op = get_op();
+        op.attributes.policy.permitted_algorithms = AsymmetricSignature::DeterministicEcdsa {
+            hash_alg: Hash::Sha256.into(),
+        }
+        .into();
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+}
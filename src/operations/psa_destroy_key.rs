@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 //! # PsaDestroyKey operation
 //!
-//! Destroy a key.
+//! Destroy a key, along with the certificate chain attached to it, if any.
 
 /// Native object for cryptographic key destruction.
 #[derive(Debug, Clone)]
@@ -4,6 +4,7 @@
 //!
 //! Generate a key or a key pair.
 
+use super::check_key_name;
 use super::psa_key_attributes::Attributes;
 
 /// Native object for creating a cryptographic key.
@@ -16,6 +17,15 @@ pub struct Operation {
     pub attributes: Attributes,
 }
 
+impl Operation {
+    /// Validate the contents of the operation
+    ///
+    /// This method checks that the key name is not reserved for key versions.
+    pub fn validate(&self) -> crate::requests::Result<()> {
+        check_key_name(&self.key_name)
+    }
+}
+
 /// Native object for the result of creating a cryptographic key.
 ///
 /// The true result is returned in the `status` field of the response.
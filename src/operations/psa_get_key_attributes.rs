@@ -0,0 +1,21 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaGetKeyAttributes operation
+//!
+//! Retrieve the attributes of a key.
+
+use super::psa_key_attributes::Attributes;
+
+/// Native object for retrieving the attributes of a key.
+#[derive(Debug, Clone)]
+pub struct Operation {
+    /// `key_name` identifies the key whose attributes are requested.
+    pub key_name: String,
+}
+
+/// Native object for the result of retrieving the attributes of a key.
+#[derive(Copy, Clone, Debug)]
+pub struct Result {
+    /// `attributes` contains the attributes of the key.
+    pub attributes: Attributes,
+}
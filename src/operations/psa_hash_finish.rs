@@ -0,0 +1,24 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaHashFinish operation
+//!
+//! Finish a multipart hash operation and return the hash of the message.
+
+use derivative::Derivative;
+
+/// Native object for hash finish operations.
+#[derive(Copy, Clone, Debug)]
+pub struct Operation {
+    /// Handle of the multipart operation, as returned by `PsaHashSetup`. The handle is no
+    /// longer valid once this operation completes.
+    pub operation_handle: u32,
+}
+
+/// Native object for hash finish result.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Result {
+    /// The `hash` field contains the hash of the message.
+    #[derivative(Debug = "ignore")]
+    pub hash: zeroize::Zeroizing<Vec<u8>>,
+}
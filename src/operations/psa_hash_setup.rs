@@ -0,0 +1,22 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaHashSetup operation
+//!
+//! Start a multipart hash operation.
+
+use crate::operations::psa_algorithm::Hash;
+
+/// Native object for hash setup operations.
+#[derive(Copy, Clone, Debug)]
+pub struct Operation {
+    /// The hash algorithm to compute.
+    pub alg: Hash,
+}
+
+/// Native object for hash setup result.
+#[derive(Copy, Clone, Debug)]
+pub struct Result {
+    /// Handle identifying the multipart operation in the service. It must be passed to the
+    /// `PsaHashUpdate`, `PsaHashFinish` and `PsaHashAbort` operations.
+    pub operation_handle: u32,
+}
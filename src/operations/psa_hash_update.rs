@@ -0,0 +1,24 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaHashUpdate operation
+//!
+//! Add a chunk of the message to a multipart hash operation.
+
+use derivative::Derivative;
+
+/// Native object for hash update operations.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Operation {
+    /// Handle of the multipart operation, as returned by `PsaHashSetup`.
+    pub operation_handle: u32,
+    /// The next chunk of the input to hash.
+    #[derivative(Debug = "ignore")]
+    pub input: zeroize::Zeroizing<Vec<u8>>,
+}
+
+/// Native object for hash update result.
+///
+/// The true result is returned in the `status` field of the response.
+#[derive(Copy, Clone, Debug)]
+pub struct Result;
@@ -4,6 +4,7 @@
 //!
 //! Import a key in binary format.
 
+use super::check_key_name;
 use super::psa_key_attributes::Attributes;
 use derivative::Derivative;
 
@@ -22,7 +23,16 @@ pub struct Operation {
     // Debug is not derived for this because it could expose secrets if printed or logged
     // somewhere
     #[derivative(Debug = "ignore")]
-    pub data: crate::secrecy::Secret<Vec<u8>>,
+    pub data: secrecy::Secret<Vec<u8>>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation
+    ///
+    /// This method checks that the key name is not reserved for key versions.
+    pub fn validate(&self) -> crate::requests::Result<()> {
+        check_key_name(&self.key_name)
+    }
 }
 
 /// Native object for the result of a cryptographic key import operation.
@@ -0,0 +1,188 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaKeyAgreement operation
+//!
+//! Perform a key agreement followed by a key derivation, storing the output as a new key.
+
+use super::check_key_name;
+use super::psa_key_attributes::Attributes;
+use crate::operations::psa_algorithm::{KeyAgreement, KeyDerivation};
+use crate::requests::ResponseStatus;
+use derivative::Derivative;
+
+/// Native object for key agreement operation.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Operation {
+    /// `alg` specifies the key agreement algorithm to use. It must be a
+    /// `KeyAgreement::WithKeyDerivation` algorithm using HKDF, as the operation does not carry
+    /// the seed and label inputs needed by the TLS-1.2 algorithms.
+    pub alg: KeyAgreement,
+    /// `private_key_name` specifies a name of the private key to use in the key agreement operation.
+    pub private_key_name: String,
+    /// `peer_key` contains the bytes of a peers public key, to be used in the key agreement operation.
+    /// This must be in the format that `PsaImportKey` accepts.
+    #[derivative(Debug = "ignore")]
+    pub peer_key: zeroize::Zeroizing<Vec<u8>>,
+    /// `key_name` specifies the name of the key that will store the derived output.
+    pub key_name: String,
+    /// `attributes` specifies the attributes of the new key. The size of the key must be set.
+    pub attributes: Attributes,
+}
+
+/// Native object for result for key agreement operation.
+#[derive(Copy, Clone, Debug)]
+pub struct Result;
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the key policy allows derivation
+    /// * the key policy allows the key agreement algorithm requested in the operation
+    /// * the key type is compatible with the requested algorithm
+    /// * the algorithm includes an HKDF key derivation step
+    /// * the attributes of the new key are valid (non-zero size, not a public key)
+    /// * the name of the new key is not reserved for key versions
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        key_attributes.can_derive_from()?;
+        key_attributes.permits_alg(self.alg.into())?;
+        key_attributes.compatible_with_alg(self.alg.into())?;
+        if !matches!(
+            self.alg,
+            KeyAgreement::WithKeyDerivation {
+                kdf_alg: KeyDerivation::Hkdf { .. },
+                ..
+            }
+        ) {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        if self.attributes.bits == 0 || self.attributes.key_type.is_public_key() {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+
+        check_key_name(&self.key_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{Algorithm, Hash, RawKeyAgreement};
+    use crate::operations::psa_key_attributes::{EccFamily, Lifetime, Policy, Type, UsageFlags};
+
+    const ALG: KeyAgreement = KeyAgreement::WithKeyDerivation {
+        ka_alg: RawKeyAgreement::Ecdh,
+        kdf_alg: KeyDerivation::Hkdf {
+            hash_alg: Hash::Sha256,
+        },
+    };
+
+    fn get_attrs() -> Attributes {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_derive();
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::EccKeyPair {
+                curve_family: EccFamily::SecpR1,
+            },
+            bits: 256,
+            policy: Policy {
+                usage_flags,
+                permitted_algorithms: ALG.into(),
+            },
+        }
+    }
+
+    fn get_op() -> Operation {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_encrypt().set_decrypt();
+        Operation {
+            alg: ALG,
+            private_key_name: String::from("some key"),
+            peer_key: vec![0xff; 65].into(),
+            key_name: String::from("session key"),
+            attributes: Attributes {
+                lifetime: Lifetime::Volatile,
+                key_type: Type::Aes,
+                bits: 128,
+                policy: Policy {
+                    usage_flags,
+                    permitted_algorithms: Algorithm::None,
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op().validate(get_attrs()).unwrap();
+    }
+
+    #[test]
+    fn cannot_derive() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags = UsageFlags::default();
+        assert_eq!(
+            get_op().validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn raw_key_agreement() {
+        let mut attrs = get_attrs();
+        attrs.policy.permitted_algorithms = KeyAgreement::Raw(RawKeyAgreement::Ecdh).into();
+        let mut op = get_op();
+        op.alg = KeyAgreement::Raw(RawKeyAgreement::Ecdh);
+        assert_eq!(
+            op.validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn tls12_key_derivation() {
+        let alg = KeyAgreement::WithKeyDerivation {
+            ka_alg: RawKeyAgreement::Ecdh,
+            kdf_alg: KeyDerivation::Tls12Prf {
+                hash_alg: Hash::Sha256,
+            },
+        };
+        let mut attrs = get_attrs();
+        attrs.policy.permitted_algorithms = alg.into();
+        let mut op = get_op();
+        op.alg = alg;
+        assert_eq!(
+            op.validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn wrong_key_type() {
+        let mut attrs = get_attrs();
+        attrs.key_type = Type::RsaKeyPair;
+        assert_eq!(
+            get_op().validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn invalid_new_key() {
+        let mut op = get_op();
+        op.attributes.bits = 0;
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+
+        let mut op = get_op();
+        op.key_name = String::from("session key@1");
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
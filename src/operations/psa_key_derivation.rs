@@ -0,0 +1,301 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaKeyDerivation operation
+//!
+//! Derive bytes or a new key from a secret key.
+
+use super::check_key_name;
+use super::psa_key_attributes::Attributes;
+use crate::operations::psa_algorithm::KeyDerivation;
+use crate::requests::ResponseStatus;
+use derivative::Derivative;
+
+/// Destination of the output of a key derivation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Output {
+    /// Return the derived bytes to the client.
+    Bytes {
+        /// Number of bytes to derive.
+        length: usize,
+    },
+    /// Store the derived output as a new key.
+    Key {
+        /// Name of the new key.
+        key_name: String,
+        /// Attributes of the new key. The size of the key must be set.
+        attributes: Attributes,
+    },
+}
+
+/// Native object for key derivation operations.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Operation {
+    /// Name of the key to use as secret input of the derivation.
+    pub key_name: String,
+    /// A key derivation algorithm that is compatible with the type of key.
+    pub alg: KeyDerivation,
+    /// Salt input, used by HKDF.
+    ///
+    /// An empty input cannot be told apart from an absent one on the wire and is not allowed.
+    #[derivative(Debug = "ignore")]
+    pub salt: Option<zeroize::Zeroizing<Vec<u8>>>,
+    /// Info input, used by HKDF.
+    ///
+    /// An empty input cannot be told apart from an absent one on the wire and is not allowed.
+    #[derivative(Debug = "ignore")]
+    pub info: Option<zeroize::Zeroizing<Vec<u8>>>,
+    /// Seed input, used by the TLS-1.2 algorithms.
+    ///
+    /// An empty input cannot be told apart from an absent one on the wire and is not allowed.
+    #[derivative(Debug = "ignore")]
+    pub seed: Option<zeroize::Zeroizing<Vec<u8>>>,
+    /// Label input, used by the TLS-1.2 algorithms.
+    ///
+    /// An empty input cannot be told apart from an absent one on the wire and is not allowed.
+    #[derivative(Debug = "ignore")]
+    pub label: Option<zeroize::Zeroizing<Vec<u8>>>,
+    /// Where the output of the derivation should go.
+    pub output: Output,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the key policy allows derivation
+    /// * the key policy allows the derivation algorithm requested in the operation
+    /// * the key type is compatible with the requested algorithm
+    /// * only the inputs used by the algorithm are given, and the mandatory ones are present
+    /// * none of the given inputs is empty
+    /// * the requested output is valid (non-zero length, or a sized private key whose name is not
+    ///   reserved for key versions)
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        key_attributes.can_derive_from()?;
+        key_attributes.permits_alg(self.alg.into())?;
+        key_attributes.compatible_with_alg(self.alg.into())?;
+
+        let inputs_valid = match self.alg {
+            KeyDerivation::Hkdf { .. } => self.seed.is_none() && self.label.is_none(),
+            KeyDerivation::Tls12Prf { .. } | KeyDerivation::Tls12PskToMs { .. } => {
+                self.salt.is_none()
+                    && self.info.is_none()
+                    && self.seed.is_some()
+                    && self.label.is_some()
+            }
+        };
+        let inputs_empty = [&self.salt, &self.info, &self.seed, &self.label]
+            .iter()
+            .any(|input| matches!(input, Some(input) if input.is_empty()));
+        if !inputs_valid || inputs_empty {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+
+        match &self.output {
+            Output::Bytes { length } if *length == 0 => {
+                Err(ResponseStatus::PsaErrorInvalidArgument)
+            }
+            Output::Key { attributes, .. }
+                if attributes.bits == 0 || attributes.key_type.is_public_key() =>
+            {
+                Err(ResponseStatus::PsaErrorInvalidArgument)
+            }
+            Output::Key { key_name, .. } => check_key_name(key_name),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Native object for key derivation result.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Result {
+    /// The derived bytes if `Output::Bytes` was requested, `None` if the output was stored as a
+    /// new key.
+    #[derivative(Debug = "ignore")]
+    pub output: Option<secrecy::Secret<Vec<u8>>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{Algorithm, Hash};
+    use crate::operations::psa_key_attributes::{EccFamily, Lifetime, Policy, Type, UsageFlags};
+
+    fn get_attrs() -> Attributes {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_derive();
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::Derive,
+            bits: 256,
+            policy: Policy {
+                usage_flags,
+                permitted_algorithms: KeyDerivation::Hkdf {
+                    hash_alg: Hash::Sha256,
+                }
+                .into(),
+            },
+        }
+    }
+
+    fn get_op() -> Operation {
+        Operation {
+            key_name: String::from("some key"),
+            alg: KeyDerivation::Hkdf {
+                hash_alg: Hash::Sha256,
+            },
+            salt: Some(vec![0xaa; 16].into()),
+            info: Some(vec![0xbb; 16].into()),
+            seed: None,
+            label: None,
+            output: Output::Bytes { length: 32 },
+        }
+    }
+
+    fn get_output_attrs() -> Attributes {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_encrypt().set_decrypt();
+        Attributes {
+            lifetime: Lifetime::Volatile,
+            key_type: Type::Aes,
+            bits: 128,
+            policy: Policy {
+                usage_flags,
+                permitted_algorithms: Algorithm::None,
+            },
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op().validate(get_attrs()).unwrap();
+    }
+
+    #[test]
+    fn validate_key_output_success() {
+        let mut op = get_op();
+        op.output = Output::Key {
+            key_name: String::from("derived key"),
+            attributes: get_output_attrs(),
+        };
+        op.validate(get_attrs()).unwrap();
+    }
+
+    #[test]
+    fn cannot_derive() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags = UsageFlags::default();
+        assert_eq!(
+            get_op().validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wrong_algorithm() {
+        let mut op = get_op();
+        op.alg = KeyDerivation::Hkdf {
+            hash_alg: Hash::Sha512,
+        };
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wrong_inputs() {
+        let mut op = get_op();
+        op.label = Some(vec![0xcc; 16].into());
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn empty_inputs() {
+        let mut op = get_op();
+        op.salt = Some(Vec::new().into());
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+        op.salt = None;
+        op.info = Some(Vec::new().into());
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+        op.info = None;
+        op.validate(get_attrs()).unwrap();
+    }
+
+    #[test]
+    fn missing_tls12_label() {
+        let mut attrs = get_attrs();
+        let alg = KeyDerivation::Tls12Prf {
+            hash_alg: Hash::Sha256,
+        };
+        attrs.policy.permitted_algorithms = alg.into();
+        let mut op = get_op();
+        op.alg = alg;
+        op.salt = None;
+        op.info = None;
+        op.seed = Some(vec![0xcc; 32].into());
+        assert_eq!(
+            op.validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+        op.label = Some(vec![0xdd; 13].into());
+        op.validate(attrs).unwrap();
+    }
+
+    #[test]
+    fn zero_length_output() {
+        let mut op = get_op();
+        op.output = Output::Bytes { length: 0 };
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn invalid_output_key() {
+        let mut op = get_op();
+        let mut attributes = get_output_attrs();
+        attributes.bits = 0;
+        op.output = Output::Key {
+            key_name: String::from("derived key"),
+            attributes,
+        };
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+
+        attributes.bits = 256;
+        attributes.key_type = Type::EccPublicKey {
+            curve_family: EccFamily::SecpR1,
+        };
+        op.output = Output::Key {
+            key_name: String::from("derived key"),
+            attributes,
+        };
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+
+        op.output = Output::Key {
+            key_name: String::from("derived key@1"),
+            attributes: get_output_attrs(),
+        };
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
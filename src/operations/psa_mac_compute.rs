@@ -0,0 +1,155 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaMacCompute operation
+//!
+//! Compute the MAC of a message.
+
+use super::psa_key_attributes::Attributes;
+use crate::operations::psa_algorithm::Mac;
+use crate::requests::ResponseStatus;
+use derivative::Derivative;
+
+/// Native object for MAC computation operations.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Operation {
+    /// Defines which key should be used for the MAC computation.
+    pub key_name: String,
+    /// A MAC algorithm that is compatible with the type of key.
+    pub alg: Mac,
+    /// Message to compute the MAC of.
+    #[derivative(Debug = "ignore")]
+    pub input: zeroize::Zeroizing<Vec<u8>>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the key policy allows signing messages
+    /// * the key policy allows the MAC algorithm requested in the operation
+    /// * the key type is compatible with the requested algorithm
+    /// * the truncated MAC length, if any, is valid (not length 0)
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        key_attributes.can_sign_message()?;
+        key_attributes.permits_alg(self.alg.into())?;
+        key_attributes.compatible_with_alg(self.alg.into())?;
+        if let Mac::Truncated { mac_length: 0, .. } = self.alg {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+
+        Ok(())
+    }
+}
+
+/// Native object for MAC computation result.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Result {
+    /// The `mac` field contains the MAC of the message.
+    #[derivative(Debug = "ignore")]
+    pub mac: zeroize::Zeroizing<Vec<u8>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{FullLengthMac, Hash};
+    use crate::operations::psa_key_attributes::{Lifetime, Policy, Type, UsageFlags};
+
+    fn get_attrs() -> Attributes {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_sign_message();
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::Hmac,
+            bits: 256,
+            policy: Policy {
+                usage_flags,
+                permitted_algorithms: Mac::FullLength(FullLengthMac::Hmac {
+                    hash_alg: Hash::Sha256,
+                })
+                .into(),
+            },
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        (Operation {
+            key_name: String::from("some key"),
+            alg: Mac::FullLength(FullLengthMac::Hmac {
+                hash_alg: Hash::Sha256,
+            }),
+            input: vec![0xff; 32].into(),
+        })
+        .validate(get_attrs())
+        .unwrap();
+    }
+
+    #[test]
+    fn cannot_sign() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags = UsageFlags::default();
+        assert_eq!(
+            (Operation {
+                key_name: String::from("some key"),
+                alg: Mac::FullLength(FullLengthMac::Hmac {
+                    hash_alg: Hash::Sha256,
+                }),
+                input: vec![0xff; 32].into(),
+            })
+            .validate(attrs)
+            .unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wrong_algorithm() {
+        assert_eq!(
+            (Operation {
+                key_name: String::from("some key"),
+                alg: Mac::FullLength(FullLengthMac::Hmac {
+                    hash_alg: Hash::Sha512,
+                }),
+                input: vec![0xff; 32].into(),
+            })
+            .validate(get_attrs())
+            .unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wrong_key_type() {
+        let mut attrs = get_attrs();
+        attrs.key_type = Type::Aes;
+        assert_eq!(
+            (Operation {
+                key_name: String::from("some key"),
+                alg: Mac::FullLength(FullLengthMac::Hmac {
+                    hash_alg: Hash::Sha256,
+                }),
+                input: vec![0xff; 32].into(),
+            })
+            .validate(attrs)
+            .unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn cmac_success() {
+        let mut attrs = get_attrs();
+        attrs.key_type = Type::Aes;
+        attrs.policy.permitted_algorithms = Mac::FullLength(FullLengthMac::Cmac).into();
+        (Operation {
+            key_name: String::from("some key"),
+            alg: Mac::FullLength(FullLengthMac::Cmac),
+            input: vec![0xff; 32].into(),
+        })
+        .validate(attrs)
+        .unwrap();
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaMacVerify operation
+//!
+//! Verify the MAC of a message.
+
+use super::psa_key_attributes::Attributes;
+use crate::operations::psa_algorithm::Mac;
+use crate::requests::ResponseStatus;
+use derivative::Derivative;
+
+/// Native object for MAC verification operations.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Operation {
+    /// Defines which key should be used for the MAC verification.
+    pub key_name: String,
+    /// A MAC algorithm that is compatible with the type of key.
+    pub alg: Mac,
+    /// Message whose MAC is to be verified.
+    #[derivative(Debug = "ignore")]
+    pub input: zeroize::Zeroizing<Vec<u8>>,
+    /// Buffer containing the expected MAC value.
+    #[derivative(Debug = "ignore")]
+    pub mac: zeroize::Zeroizing<Vec<u8>>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the key policy allows verifying messages
+    /// * the key policy allows the MAC algorithm requested in the operation
+    /// * the key type is compatible with the requested algorithm
+    /// * the MAC to verify is valid (not length 0)
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        key_attributes.can_verify_message()?;
+        key_attributes.permits_alg(self.alg.into())?;
+        key_attributes.compatible_with_alg(self.alg.into())?;
+        if self.mac.is_empty() {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+
+        Ok(())
+    }
+}
+
+/// Native object for MAC verification result.
+///
+/// The true result of the operation is sent as a `status` code in the response.
+#[derive(Copy, Clone, Debug)]
+pub struct Result;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{FullLengthMac, Hash};
+    use crate::operations::psa_key_attributes::{Lifetime, Policy, Type, UsageFlags};
+
+    fn get_attrs() -> Attributes {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_verify_message();
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::Hmac,
+            bits: 256,
+            policy: Policy {
+                usage_flags,
+                permitted_algorithms: Mac::FullLength(FullLengthMac::Hmac {
+                    hash_alg: Hash::Sha256,
+                })
+                .into(),
+            },
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        (Operation {
+            key_name: String::from("some key"),
+            alg: Mac::FullLength(FullLengthMac::Hmac {
+                hash_alg: Hash::Sha256,
+            }),
+            input: vec![0xff; 32].into(),
+            mac: vec![0xaa; 32].into(),
+        })
+        .validate(get_attrs())
+        .unwrap();
+    }
+
+    #[test]
+    fn cannot_verify() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags = UsageFlags::default();
+        assert_eq!(
+            (Operation {
+                key_name: String::from("some key"),
+                alg: Mac::FullLength(FullLengthMac::Hmac {
+                    hash_alg: Hash::Sha256,
+                }),
+                input: vec![0xff; 32].into(),
+                mac: vec![0xaa; 32].into(),
+            })
+            .validate(attrs)
+            .unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wrong_algorithm() {
+        assert_eq!(
+            (Operation {
+                key_name: String::from("some key"),
+                alg: Mac::FullLength(FullLengthMac::Hmac {
+                    hash_alg: Hash::Sha384,
+                }),
+                input: vec![0xff; 32].into(),
+                mac: vec![0xaa; 48].into(),
+            })
+            .validate(get_attrs())
+            .unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn invalid_mac() {
+        assert_eq!(
+            (Operation {
+                key_name: String::from("some key"),
+                alg: Mac::FullLength(FullLengthMac::Hmac {
+                    hash_alg: Hash::Sha256,
+                }),
+                input: vec![0xff; 32].into(),
+                mac: vec![].into(),
+            })
+            .validate(get_attrs())
+            .unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
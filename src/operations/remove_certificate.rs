@@ -0,0 +1,18 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # RemoveCertificate operation
+//!
+//! Remove the chain of X.509 certificates attached to a key. The key itself is not affected.
+
+/// Native object for removing the certificate chain of a key.
+#[derive(Clone, Debug)]
+pub struct Operation {
+    /// Name of the key whose certificate chain is removed.
+    pub key_name: String,
+}
+
+/// Native object for the result of removing the certificate chain of a key.
+///
+/// The true result is returned in the `status` field of the response.
+#[derive(Copy, Clone, Debug)]
+pub struct Result;
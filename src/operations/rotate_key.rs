@@ -0,0 +1,241 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # RotateKey operation
+//!
+//! Atomically generate a new version of a key, with the same attributes as the current one.
+//!
+//! The versions of a key are addressed as `name@N`, `N` starting at 1 for the key as it was
+//! originally created. The unversioned name always refers to the latest version. Previous versions
+//! remain available, but their policy is restricted to verification and decryption usages (see
+//! [`retired_usage_flags`]).
+//!
+//! Names containing [`VERSION_SEPARATOR`] are reserved for key versions: the operations creating
+//! keys reject them. A key created under such a name before being reserved keeps being addressed
+//! by it, but can not be rotated.
+
+use super::psa_generate_key;
+use super::psa_key_attributes::{Attributes, UsageFlags};
+use super::{check_key_name, VERSION_SEPARATOR};
+use crate::requests::ResponseStatus;
+
+/// Build the name addressing a specific version of a key
+pub fn versioned_name(key_name: &str, version: u32) -> String {
+    format!("{}{}{}", key_name, VERSION_SEPARATOR, version)
+}
+
+/// Split a key name into the unversioned name and the version it addresses
+///
+/// Returns `None` as version if the name does not end with a valid `@N` suffix, in which case
+/// the name refers to the latest version of the key. Only the canonical form of the version
+/// number, without leading zeros, is valid so that each version has a single name.
+pub fn parse_versioned_name(key_name: &str) -> (&str, Option<u32>) {
+    if let Some((name, version)) = key_name.rsplit_once(VERSION_SEPARATOR) {
+        if !name.is_empty()
+            && !version.starts_with('0')
+            && version.chars().all(|c| c.is_ascii_digit())
+        {
+            if let Ok(version) = version.parse::<u32>() {
+                if version > 0 {
+                    return (name, Some(version));
+                }
+            }
+        }
+    }
+    (key_name, None)
+}
+
+/// Usage flags of the previous versions of a rotated key
+///
+/// Only the verification and decryption usages allowed by `usage_flags` are kept.
+pub fn retired_usage_flags(usage_flags: UsageFlags) -> UsageFlags {
+    let mut retired = UsageFlags::default();
+    if usage_flags.verify_hash() {
+        let _ = retired.set_verify_hash();
+    }
+    if usage_flags.verify_message() {
+        let _ = retired.set_verify_message();
+    }
+    if usage_flags.decrypt() {
+        let _ = retired.set_decrypt();
+    }
+    retired
+}
+
+/// Native object for key rotation operation.
+#[derive(Clone, Debug)]
+pub struct Operation {
+    /// Unversioned name of the key to rotate.
+    pub key_name: String,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the key name is not reserved for key versions
+    /// * the key is not a public key, which can not be generated
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        check_key_name(&self.key_name)?;
+        if key_attributes.key_type.is_public_key() {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+
+        Ok(())
+    }
+
+    /// Create the operation generating the version `version` of the key, given the attributes of
+    /// its current version
+    ///
+    /// The name of the key to generate is a versioned name, which is reserved: the returned
+    /// operation does not pass its own validation.
+    pub fn generate_key_operation(
+        &self,
+        version: u32,
+        key_attributes: Attributes,
+    ) -> psa_generate_key::Operation {
+        psa_generate_key::Operation {
+            key_name: versioned_name(&self.key_name, version),
+            attributes: key_attributes,
+        }
+    }
+}
+
+/// Native object for key rotation result.
+#[derive(Copy, Clone, Debug)]
+pub struct Result {
+    /// Version number of the newly generated key, which is now the latest version.
+    pub version: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, Hash};
+    use crate::operations::psa_key_attributes::{EccFamily, Lifetime, Policy, Type};
+
+    fn get_attrs() -> Attributes {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_sign_hash().set_verify_hash().set_export();
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::EccKeyPair {
+                curve_family: EccFamily::SecpR1,
+            },
+            bits: 256,
+            policy: Policy {
+                usage_flags,
+                permitted_algorithms: Algorithm::AsymmetricSignature(AsymmetricSignature::Ecdsa {
+                    hash_alg: Hash::Sha256.into(),
+                }),
+            },
+        }
+    }
+
+    #[test]
+    fn versioned_names() {
+        assert_eq!(versioned_name("key", 3), "key@3");
+        assert_eq!(parse_versioned_name("key@3"), ("key", Some(3)));
+        assert_eq!(
+            parse_versioned_name("user@host@12"),
+            ("user@host", Some(12))
+        );
+        assert_eq!(parse_versioned_name("key"), ("key", None));
+        assert_eq!(parse_versioned_name("key@"), ("key@", None));
+        assert_eq!(parse_versioned_name("key@0"), ("key@0", None));
+        assert_eq!(parse_versioned_name("key@01"), ("key@01", None));
+        assert_eq!(parse_versioned_name("key@10"), ("key", Some(10)));
+        assert_eq!(parse_versioned_name("key@+1"), ("key@+1", None));
+        assert_eq!(parse_versioned_name("user@host"), ("user@host", None));
+        assert_eq!(parse_versioned_name("@1"), ("@1", None));
+    }
+
+    #[test]
+    fn reserved_names() {
+        check_key_name("key").unwrap();
+        assert_eq!(
+            check_key_name("key@3").unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+        assert_eq!(
+            check_key_name("user@host").unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+
+        let op = psa_generate_key::Operation {
+            key_name: String::from("key@01"),
+            attributes: get_attrs(),
+        };
+        assert_eq!(
+            op.validate().unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn pre_existing_versioned_key() {
+        // A key named "backup@3" created before names containing the separator were reserved is
+        // still reachable under its name, which is the one of the third version of "backup".
+        assert_eq!(parse_versioned_name("backup@3"), ("backup", Some(3)));
+        assert_eq!(versioned_name("backup", 3), "backup@3");
+
+        // It can not be rotated, as a version of "backup" or under its own name.
+        let op = Operation {
+            key_name: String::from("backup@3"),
+        };
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn retired_usages() {
+        let retired = retired_usage_flags(get_attrs().policy.usage_flags);
+        assert!(retired.verify_hash());
+        assert!(retired.verify_message());
+        assert!(!retired.sign_hash());
+        assert!(!retired.sign_message());
+        assert!(!retired.export());
+
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_encrypt().set_decrypt();
+        let retired = retired_usage_flags(usage_flags);
+        assert!(retired.decrypt());
+        assert!(!retired.encrypt());
+    }
+
+    #[test]
+    fn validate() {
+        let op = Operation {
+            key_name: String::from("key"),
+        };
+        op.validate(get_attrs()).unwrap();
+
+        let mut attrs = get_attrs();
+        attrs.key_type = Type::EccPublicKey {
+            curve_family: EccFamily::SecpR1,
+        };
+        assert_eq!(
+            op.validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+
+        let op = Operation {
+            key_name: String::from("key@2"),
+        };
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn generate_key_operation() {
+        let op = Operation {
+            key_name: String::from("key"),
+        };
+        let generate_op = op.generate_key_operation(2, get_attrs());
+        assert_eq!(generate_op.key_name, "key@2");
+        assert_eq!(generate_op.attributes, get_attrs());
+    }
+}
@@ -0,0 +1,315 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::attest_key::{
+    attestation_mechanism_params, attestation_output, AttestationMechanismParams,
+    AttestationOutput, Operation as OperationProto, Result as ResultProto,
+};
+use crate::operations::attest_key::{MechanismParams, MechanismResult, Operation, Result};
+use crate::requests::ResponseStatus;
+use log::error;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<AttestationMechanismParams> for MechanismParams {
+    type Error = ResponseStatus;
+
+    fn try_from(
+        proto_params: AttestationMechanismParams,
+    ) -> std::result::Result<Self, Self::Error> {
+        match proto_params.mechanism {
+            Some(attestation_mechanism_params::Mechanism::ActivateCredential(
+                attestation_mechanism_params::ActivateCredential { credential, secret },
+            )) => Ok(MechanismParams::ActivateCredential {
+                credential: credential.into(),
+                secret: secret.into(),
+            }),
+            Some(attestation_mechanism_params::Mechanism::Certify(
+                attestation_mechanism_params::Certify { nonce },
+            )) => Ok(MechanismParams::Certify { nonce }),
+            None => {
+                error!("The mechanism field of AttestKey::AttestationMechanismParams message is not set (mandatory field).");
+                Err(ResponseStatus::InvalidEncoding)
+            }
+        }
+    }
+}
+
+impl From<MechanismParams> for AttestationMechanismParams {
+    fn from(params: MechanismParams) -> Self {
+        let mechanism = match params {
+            MechanismParams::ActivateCredential { credential, secret } => {
+                attestation_mechanism_params::Mechanism::ActivateCredential(
+                    attestation_mechanism_params::ActivateCredential {
+                        credential: credential.to_vec(),
+                        secret: secret.to_vec(),
+                    },
+                )
+            }
+            MechanismParams::Certify { nonce } => attestation_mechanism_params::Mechanism::Certify(
+                attestation_mechanism_params::Certify { nonce },
+            ),
+        };
+        AttestationMechanismParams {
+            mechanism: Some(mechanism),
+        }
+    }
+}
+
+impl TryFrom<AttestationOutput> for MechanismResult {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_output: AttestationOutput) -> std::result::Result<Self, Self::Error> {
+        match proto_output.mechanism {
+            Some(attestation_output::Mechanism::ActivateCredential(
+                attestation_output::ActivateCredential { credential },
+            )) => Ok(MechanismResult::ActivateCredential {
+                credential: credential.into(),
+            }),
+            Some(attestation_output::Mechanism::Certify(attestation_output::Certify {
+                attestation,
+                signature,
+            })) => Ok(MechanismResult::Certify {
+                attestation,
+                signature,
+            }),
+            None => {
+                error!("The mechanism field of AttestKey::AttestationOutput message is not set (mandatory field).");
+                Err(ResponseStatus::InvalidEncoding)
+            }
+        }
+    }
+}
+
+impl From<MechanismResult> for AttestationOutput {
+    fn from(output: MechanismResult) -> Self {
+        let mechanism = match output {
+            MechanismResult::ActivateCredential { credential } => {
+                attestation_output::Mechanism::ActivateCredential(
+                    attestation_output::ActivateCredential {
+                        credential: credential.to_vec(),
+                    },
+                )
+            }
+            MechanismResult::Certify {
+                attestation,
+                signature,
+            } => attestation_output::Mechanism::Certify(attestation_output::Certify {
+                attestation,
+                signature,
+            }),
+        };
+        AttestationOutput {
+            mechanism: Some(mechanism),
+        }
+    }
+}
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            attested_key_name: proto_op.attested_key_name,
+            parameters: proto_op
+                .parameters
+                .ok_or_else(|| {
+                    error!("The parameters field of AttestKey::Operation message is not set (mandatory field).");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+            attesting_key_name: if proto_op.attesting_key_name.is_empty() {
+                None
+            } else {
+                Some(proto_op.attesting_key_name)
+            },
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            attested_key_name: op.attested_key_name,
+            parameters: Some(op.parameters.into()),
+            attesting_key_name: op.attesting_key_name.unwrap_or_default(),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            output: proto_result
+                .output
+                .ok_or_else(|| {
+                    error!("The output field of AttestKey::Result message is not set (mandatory field).");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            output: Some(result.output.into()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::attest_key::{
+        AttestationMechanismParams, AttestationOutput, Operation as OperationProto,
+        Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::attest_key::{MechanismParams, MechanismResult, Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode, ResponseStatus};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn attest_key_activate_credential_round_trip() {
+        let op = Operation {
+            attested_key_name: "attested key".to_string(),
+            parameters: MechanismParams::ActivateCredential {
+                credential: vec![0x11, 0x22].into(),
+                secret: vec![0x33, 0x44].into(),
+            },
+            attesting_key_name: None,
+        };
+
+        let proto: OperationProto = op.try_into().expect("Failed conversion");
+        assert!(proto.attesting_key_name.is_empty());
+        let op: Operation = proto.try_into().expect("Failed conversion");
+
+        assert_eq!(op.attested_key_name, "attested key");
+        assert!(op.attesting_key_name.is_none());
+        match op.parameters {
+            MechanismParams::ActivateCredential { credential, secret } => {
+                assert_eq!(*credential, vec![0x11, 0x22]);
+                assert_eq!(*secret, vec![0x33, 0x44]);
+            }
+            _ => panic!("Wrong mechanism"),
+        }
+    }
+
+    #[test]
+    fn attest_key_certify_round_trip() {
+        let op = Operation {
+            attested_key_name: "attested key".to_string(),
+            parameters: MechanismParams::Certify {
+                nonce: vec![0x55; 32],
+            },
+            attesting_key_name: Some("attesting key".to_string()),
+        };
+
+        let proto: OperationProto = op.try_into().expect("Failed conversion");
+        let op: Operation = proto.try_into().expect("Failed conversion");
+
+        assert_eq!(op.attesting_key_name, Some("attesting key".to_string()));
+        match op.parameters {
+            MechanismParams::Certify { nonce } => assert_eq!(nonce, vec![0x55; 32]),
+            _ => panic!("Wrong mechanism"),
+        }
+
+        let result = Result {
+            output: MechanismResult::Certify {
+                attestation: vec![0x66, 0x77],
+                signature: vec![0x88, 0x99],
+            },
+        };
+        let proto: ResultProto = result.try_into().expect("Failed conversion");
+        let result: Result = proto.try_into().expect("Failed conversion");
+        match result.output {
+            MechanismResult::Certify {
+                attestation,
+                signature,
+            } => {
+                assert_eq!(attestation, vec![0x66, 0x77]);
+                assert_eq!(signature, vec![0x88, 0x99]);
+            }
+            _ => panic!("Wrong mechanism"),
+        }
+    }
+
+    #[test]
+    fn attest_key_missing_fields() {
+        let proto = OperationProto {
+            attested_key_name: "attested key".to_string(),
+            parameters: None,
+            attesting_key_name: String::new(),
+        };
+        let op: std::result::Result<Operation, ResponseStatus> = proto.try_into();
+        assert_eq!(op.unwrap_err(), ResponseStatus::InvalidEncoding);
+
+        let proto = OperationProto {
+            attested_key_name: "attested key".to_string(),
+            parameters: Some(AttestationMechanismParams { mechanism: None }),
+            attesting_key_name: String::new(),
+        };
+        let op: std::result::Result<Operation, ResponseStatus> = proto.try_into();
+        assert_eq!(op.unwrap_err(), ResponseStatus::InvalidEncoding);
+
+        let proto = ResultProto {
+            output: Some(AttestationOutput { mechanism: None }),
+        };
+        let result: std::result::Result<Result, ResponseStatus> = proto.try_into();
+        assert_eq!(result.unwrap_err(), ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn attest_key_e2e() {
+        let op = Operation {
+            attested_key_name: "attested key".to_string(),
+            parameters: MechanismParams::Certify {
+                nonce: vec![0x55; 32],
+            },
+            attesting_key_name: None,
+        };
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::AttestKey(op))
+            .expect("Failed to convert to body");
+        let _ = CONVERTER
+            .body_to_operation(body, Opcode::AttestKey)
+            .expect("Failed to convert to operation");
+
+        let result = Result {
+            output: MechanismResult::ActivateCredential {
+                credential: vec![0x11, 0x22].into(),
+            },
+        };
+        let body = CONVERTER
+            .result_to_body(NativeResult::AttestKey(result))
+            .expect("Failed to convert to body");
+        assert!(CONVERTER.body_to_result(body, Opcode::AttestKey).is_ok());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::AttestKey)
+            .is_err());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::AttestKey)
+            .is_err());
+    }
+}
@@ -0,0 +1,362 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::batch::{
+    BatchItem as BatchItemProto, BatchItemResult as BatchItemResultProto,
+    Operation as OperationProto, Result as ResultProto,
+};
+use super::{Convert, ProtobufConverter};
+use crate::operations::batch::{ItemFailure, Operation, Result};
+use crate::requests::{request::RequestBody, response::ResponseBody, Opcode, ResponseStatus};
+use log::error;
+use num::FromPrimitive;
+use std::convert::{TryFrom, TryInto};
+
+static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+// Only crypto operations can be batched: the batch itself is dispatched as one, so core and admin
+// operations would reach the wrong provider and skip the admin checks.
+fn check_item_opcode(opcode: Opcode) -> std::result::Result<Opcode, ResponseStatus> {
+    if opcode == Opcode::Batch {
+        error!("Batch operations can not be nested.");
+        Err(ResponseStatus::InvalidEncoding)
+    } else if opcode.is_core() || opcode.is_admin() {
+        error!(
+            "Core and admin operations can not be batched (opcode {:?}).",
+            opcode
+        );
+        Err(ResponseStatus::InvalidEncoding)
+    } else {
+        Ok(opcode)
+    }
+}
+
+fn u32_to_opcode(opcode: u32) -> std::result::Result<Opcode, ResponseStatus> {
+    match FromPrimitive::from_u32(opcode) {
+        Some(opcode) => check_item_opcode(opcode),
+        None => Err(ResponseStatus::OpcodeDoesNotExist),
+    }
+}
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        let mut operations = Vec::new();
+        for item in proto_op.operations {
+            let opcode = u32_to_opcode(item.opcode)?;
+            operations
+                .push(CONVERTER.body_to_operation(RequestBody::from_bytes(item.body), opcode)?);
+        }
+
+        Ok(Operation { operations })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        let mut operations = Vec::new();
+        for operation in op.operations {
+            let opcode = check_item_opcode(operation.opcode())?;
+            operations.push(BatchItemProto {
+                opcode: opcode as u32,
+                body: CONVERTER.operation_to_body(operation)?.bytes().to_vec(),
+            });
+        }
+
+        Ok(OperationProto { operations })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        let mut results = Vec::new();
+        for item in proto_result.results {
+            let status: u16 = item.status.try_into()?;
+            let status: ResponseStatus = status.try_into()?;
+            let opcode = u32_to_opcode(item.opcode)?;
+            if status == ResponseStatus::Success {
+                results.push(Ok(
+                    CONVERTER.body_to_result(ResponseBody::from_bytes(item.body), opcode)?
+                ));
+            } else {
+                results.push(Err(ItemFailure { opcode, status }));
+            }
+        }
+
+        Ok(Result { results })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        let mut results = Vec::new();
+        for item in result.results {
+            results.push(match item {
+                Ok(result) => BatchItemResultProto {
+                    opcode: check_item_opcode(result.opcode())? as u32,
+                    status: ResponseStatus::Success as u32,
+                    body: CONVERTER.result_to_body(result)?.bytes().to_vec(),
+                },
+                Err(failure) => BatchItemResultProto {
+                    opcode: check_item_opcode(failure.opcode)? as u32,
+                    status: failure.status as u32,
+                    body: Vec::new(),
+                },
+            });
+        }
+
+        Ok(ResultProto { results })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::batch::{
+        BatchItem as BatchItemProto, Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::batch::{ItemFailure, Operation, Result};
+    use crate::operations::psa_algorithm::Hash;
+    use crate::operations::{
+        delete_client, list_clients, psa_generate_random, psa_hash_compute, psa_sign_hash,
+        NativeOperation, NativeResult,
+    };
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode, ResponseStatus};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_op() -> Operation {
+        Operation {
+            operations: vec![
+                NativeOperation::PsaGenerateRandom(psa_generate_random::Operation { size: 32 }),
+                NativeOperation::PsaHashCompute(psa_hash_compute::Operation {
+                    alg: Hash::Sha256,
+                    input: vec![0x11, 0x22, 0x33].into(),
+                }),
+            ],
+        }
+    }
+
+    #[test]
+    fn batch_op_round_trip() {
+        let proto: OperationProto = get_op().try_into().expect("Failed conversion");
+        assert_eq!(proto.operations.len(), 2);
+        assert_eq!(proto.operations[0].opcode, Opcode::PsaGenerateRandom as u32);
+        assert_eq!(proto.operations[1].opcode, Opcode::PsaHashCompute as u32);
+
+        let op: Operation = proto.try_into().expect("Failed conversion");
+        assert_eq!(op.operations.len(), 2);
+        assert_eq!(op.operations[0].opcode(), Opcode::PsaGenerateRandom);
+        match &op.operations[1] {
+            NativeOperation::PsaHashCompute(hash_op) => {
+                assert_eq!(hash_op.alg, Hash::Sha256);
+                assert_eq!(*hash_op.input, vec![0x11, 0x22, 0x33]);
+            }
+            _ => panic!("Expected a PsaHashCompute operation"),
+        }
+    }
+
+    #[test]
+    fn nested_batch() {
+        let op = Operation {
+            operations: vec![NativeOperation::Batch(get_op())],
+        };
+        let proto: std::result::Result<OperationProto, ResponseStatus> = op.try_into();
+        assert_eq!(proto.unwrap_err(), ResponseStatus::InvalidEncoding);
+
+        let proto = OperationProto {
+            operations: vec![BatchItemProto {
+                opcode: Opcode::Batch as u32,
+                body: Vec::new(),
+            }],
+        };
+        let op: std::result::Result<Operation, ResponseStatus> = proto.try_into();
+        assert_eq!(op.unwrap_err(), ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn admin_items() {
+        for operation in [
+            NativeOperation::ListClients(list_clients::Operation {
+                page_token: None,
+                limit: None,
+            }),
+            NativeOperation::DeleteClient(delete_client::Operation {
+                client: String::from("some client"),
+                dry_run: false,
+            }),
+        ] {
+            let opcode = operation.opcode();
+            let op = Operation {
+                operations: vec![operation],
+            };
+            let proto: std::result::Result<OperationProto, ResponseStatus> = op.try_into();
+            assert_eq!(proto.unwrap_err(), ResponseStatus::InvalidEncoding);
+
+            let proto = OperationProto {
+                operations: vec![BatchItemProto {
+                    opcode: opcode as u32,
+                    body: Vec::new(),
+                }],
+            };
+            let op: std::result::Result<Operation, ResponseStatus> = proto.try_into();
+            assert_eq!(op.unwrap_err(), ResponseStatus::InvalidEncoding);
+
+            let result = Result {
+                results: vec![Err(ItemFailure {
+                    opcode,
+                    status: ResponseStatus::AdminOperation,
+                })],
+            };
+            let proto: std::result::Result<ResultProto, ResponseStatus> = result.try_into();
+            assert_eq!(proto.unwrap_err(), ResponseStatus::InvalidEncoding);
+        }
+    }
+
+    #[test]
+    fn core_items() {
+        let proto = OperationProto {
+            operations: vec![BatchItemProto {
+                opcode: Opcode::Ping as u32,
+                body: Vec::new(),
+            }],
+        };
+        let op: std::result::Result<Operation, ResponseStatus> = proto.try_into();
+        assert_eq!(op.unwrap_err(), ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn invalid_item_proto() {
+        let proto = OperationProto {
+            operations: vec![BatchItemProto {
+                opcode: 0xffff,
+                body: Vec::new(),
+            }],
+        };
+        let op: std::result::Result<Operation, ResponseStatus> = proto.try_into();
+        assert_eq!(op.unwrap_err(), ResponseStatus::OpcodeDoesNotExist);
+
+        let proto = OperationProto {
+            operations: vec![BatchItemProto {
+                opcode: Opcode::PsaSignHash as u32,
+                body: vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88],
+            }],
+        };
+        let op: std::result::Result<Operation, ResponseStatus> = proto.try_into();
+        assert!(op.is_err());
+    }
+
+    #[test]
+    fn batch_result_round_trip() {
+        let result = Result {
+            results: vec![
+                Ok(NativeResult::PsaSignHash(psa_sign_hash::Result {
+                    signature: vec![0x11, 0x22].into(),
+                })),
+                Err(ItemFailure {
+                    opcode: Opcode::PsaAsymmetricDecrypt,
+                    status: ResponseStatus::PsaErrorNotPermitted,
+                }),
+                Ok(NativeResult::PsaGenerateRandom(
+                    psa_generate_random::Result {
+                        random_bytes: vec![0x33, 0x44].into(),
+                    },
+                )),
+            ],
+        };
+
+        let proto: ResultProto = result.try_into().expect("Failed conversion");
+        assert_eq!(proto.results.len(), 3);
+        assert_eq!(proto.results[1].opcode, Opcode::PsaAsymmetricDecrypt as u32);
+        assert_eq!(
+            proto.results[1].status,
+            ResponseStatus::PsaErrorNotPermitted as u32
+        );
+
+        let result: Result = proto.try_into().expect("Failed conversion");
+        assert_eq!(result.results.len(), 3);
+        match &result.results[0] {
+            Ok(NativeResult::PsaSignHash(sign_result)) => {
+                assert_eq!(*sign_result.signature, vec![0x11, 0x22])
+            }
+            _ => panic!("Expected a PsaSignHash result"),
+        }
+        assert_eq!(
+            result.results[1].as_ref().unwrap_err(),
+            &ItemFailure {
+                opcode: Opcode::PsaAsymmetricDecrypt,
+                status: ResponseStatus::PsaErrorNotPermitted,
+            }
+        );
+        assert_eq!(
+            result.results[2].as_ref().unwrap().opcode(),
+            Opcode::PsaGenerateRandom
+        );
+    }
+
+    #[test]
+    fn batch_op_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::Batch(get_op()))
+            .expect("Failed to convert to body");
+
+        match CONVERTER
+            .body_to_operation(body, Opcode::Batch)
+            .expect("Failed to convert to operation")
+        {
+            NativeOperation::Batch(op) => assert_eq!(op.operations.len(), 2),
+            _ => panic!("Expected a Batch operation"),
+        }
+    }
+
+    #[test]
+    fn batch_res_e2e() {
+        let result = Result {
+            results: vec![Err(ItemFailure {
+                opcode: Opcode::PsaDestroyKey,
+                status: ResponseStatus::PsaErrorDoesNotExist,
+            })],
+        };
+        let body = CONVERTER
+            .result_to_body(NativeResult::Batch(result))
+            .expect("Failed to convert to body");
+
+        match CONVERTER
+            .body_to_result(body, Opcode::Batch)
+            .expect("Failed to convert to result")
+        {
+            NativeResult::Batch(result) => assert_eq!(
+                result.results[0].as_ref().unwrap_err(),
+                &ItemFailure {
+                    opcode: Opcode::PsaDestroyKey,
+                    status: ResponseStatus::PsaErrorDoesNotExist,
+                }
+            ),
+            _ => panic!("Expected a Batch result"),
+        }
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::Batch)
+            .is_err());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER.body_to_result(resp_body, Opcode::Batch).is_err());
+    }
+}
@@ -0,0 +1,256 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::convert_list_keys::u32_to_provider_id;
+use super::generated_ops::can_do_crypto::{
+    CheckType as CheckTypeProto, Operation as OperationProto, Provider as ProviderProto,
+    Result as ResultProto,
+};
+use crate::operations::can_do_crypto::{CheckType, Operation, Result};
+use crate::requests::ResponseStatus;
+use log::error;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<i32> for CheckType {
+    type Error = ResponseStatus;
+
+    fn try_from(check_type_val: i32) -> std::result::Result<Self, Self::Error> {
+        match CheckTypeProto::from_i32(check_type_val) {
+            Some(CheckTypeProto::Use) => Ok(CheckType::Use),
+            Some(CheckTypeProto::Generate) => Ok(CheckType::Generate),
+            Some(CheckTypeProto::Import) => Ok(CheckType::Import),
+            Some(CheckTypeProto::Derive) => Ok(CheckType::Derive),
+            Some(CheckTypeProto::None) => {
+                error!("The None value of CheckType enumeration is not allowed (mandatory field).");
+                Err(ResponseStatus::InvalidEncoding)
+            }
+            None => {
+                error!(
+                    "Value {} not supported as a check type encoding.",
+                    check_type_val
+                );
+                Err(ResponseStatus::InvalidEncoding)
+            }
+        }
+    }
+}
+
+impl From<CheckType> for i32 {
+    fn from(check_type: CheckType) -> Self {
+        match check_type {
+            CheckType::Use => CheckTypeProto::Use.into(),
+            CheckType::Generate => CheckTypeProto::Generate.into(),
+            CheckType::Import => CheckTypeProto::Import.into(),
+            CheckType::Derive => CheckTypeProto::Derive.into(),
+        }
+    }
+}
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            check_type: proto_op.check_type.try_into()?,
+            attributes: proto_op
+                .attributes
+                .ok_or_else(|| {
+                    error!("The attributes field of CanDoCrypto::Operation message is not set (mandatory field).");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+            provider_id: proto_op
+                .provider
+                .map(|provider| u32_to_provider_id(provider.provider_id))
+                .transpose()?,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            check_type: op.check_type.into(),
+            attributes: Some(op.attributes.try_into()?),
+            provider: op.provider_id.map(|provider_id| ProviderProto {
+                provider_id: provider_id as u32,
+            }),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(_proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {})
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(_result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {})
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::can_do_crypto::{
+        CheckType as CheckTypeProto, Operation as OperationProto, Provider as ProviderProto,
+        Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::can_do_crypto::{CheckType, Operation, Result};
+    use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, Hash};
+    use crate::operations::psa_key_attributes::{
+        Attributes, EccFamily, Lifetime, Policy, Type, UsageFlags,
+    };
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{
+        request::RequestBody, response::ResponseBody, Opcode, ProviderId, ResponseStatus,
+    };
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_attrs() -> Attributes {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_sign_hash();
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::EccKeyPair {
+                curve_family: EccFamily::SecpR1,
+            },
+            bits: 256,
+            policy: Policy {
+                usage_flags,
+                permitted_algorithms: Algorithm::AsymmetricSignature(AsymmetricSignature::Ecdsa {
+                    hash_alg: Hash::Sha256.into(),
+                }),
+            },
+        }
+    }
+
+    #[test]
+    fn can_do_crypto_op_round_trip() {
+        for check_type in [
+            CheckType::Use,
+            CheckType::Generate,
+            CheckType::Import,
+            CheckType::Derive,
+        ] {
+            let op = Operation {
+                check_type,
+                attributes: get_attrs(),
+                provider_id: None,
+            };
+
+            let proto: OperationProto = op.try_into().expect("Failed conversion");
+            let op: Operation = proto.try_into().expect("Failed conversion");
+
+            assert_eq!(op.check_type, check_type);
+            assert_eq!(op.attributes, get_attrs());
+            assert_eq!(op.provider_id, None);
+        }
+    }
+
+    #[test]
+    fn can_do_crypto_provider_round_trip() {
+        for provider_id in [ProviderId::Core, ProviderId::MbedCrypto, ProviderId::Tpm] {
+            let op = Operation {
+                check_type: CheckType::Use,
+                attributes: get_attrs(),
+                provider_id: Some(provider_id),
+            };
+
+            let proto: OperationProto = op.try_into().expect("Failed conversion");
+            let op: Operation = proto.try_into().expect("Failed conversion");
+
+            assert_eq!(op.provider_id, Some(provider_id));
+        }
+    }
+
+    #[test]
+    fn can_do_crypto_invalid_proto() {
+        let proto: OperationProto = Operation {
+            check_type: CheckType::Generate,
+            attributes: get_attrs(),
+            provider_id: None,
+        }
+        .try_into()
+        .expect("Failed conversion");
+
+        let mut none_check_type = proto.clone();
+        none_check_type.check_type = CheckTypeProto::None.into();
+        let op: std::result::Result<Operation, ResponseStatus> = none_check_type.try_into();
+        assert_eq!(op.unwrap_err(), ResponseStatus::InvalidEncoding);
+
+        let mut unknown_check_type = proto.clone();
+        unknown_check_type.check_type = 42;
+        let op: std::result::Result<Operation, ResponseStatus> = unknown_check_type.try_into();
+        assert_eq!(op.unwrap_err(), ResponseStatus::InvalidEncoding);
+
+        let mut missing_attributes = proto.clone();
+        missing_attributes.attributes = None;
+        let op: std::result::Result<Operation, ResponseStatus> = missing_attributes.try_into();
+        assert_eq!(op.unwrap_err(), ResponseStatus::InvalidEncoding);
+
+        let mut unknown_provider = proto;
+        unknown_provider.provider = Some(ProviderProto { provider_id: 42 });
+        let op: std::result::Result<Operation, ResponseStatus> = unknown_provider.try_into();
+        assert_eq!(op.unwrap_err(), ResponseStatus::ProviderDoesNotExist);
+    }
+
+    #[test]
+    fn can_do_crypto_res_conversions() {
+        let _res: Result = ResultProto {}.try_into().expect("Failed conversion");
+        let _proto: ResultProto = Result {}.try_into().expect("Failed conversion");
+    }
+
+    #[test]
+    fn can_do_crypto_op_e2e() {
+        let op = Operation {
+            check_type: CheckType::Use,
+            attributes: get_attrs(),
+            provider_id: Some(ProviderId::MbedCrypto),
+        };
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::CanDoCrypto(op))
+            .expect("Failed to convert to body");
+
+        let _ = CONVERTER
+            .body_to_operation(body, Opcode::CanDoCrypto)
+            .expect("Failed to convert to operation");
+    }
+
+    #[test]
+    fn can_do_crypto_res_e2e() {
+        let body = CONVERTER
+            .result_to_body(NativeResult::CanDoCrypto(Result {}))
+            .expect("Failed to convert to body");
+
+        assert!(CONVERTER.body_to_result(body, Opcode::CanDoCrypto).is_ok());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::CanDoCrypto)
+            .is_err());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::CanDoCrypto)
+            .is_err());
+    }
+}
@@ -1,58 +1,239 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use super::generated_ops::delete_client::{Operation as OperationProto, Result as ResultProto};
-use crate::operations::delete_client::{Operation, Result};
+use super::generated_ops::delete_client::{
+    KeyDeletionFailure as KeyDeletionFailureProto, Operation as OperationProto,
+    Result as ResultProto,
+};
+use crate::operations::delete_client::{KeyDeletionFailure, Operation, Result};
+use crate::requests::ResponseStatus;
+use log::error;
+use std::convert::{TryFrom, TryInto};
 
 impl From<OperationProto> for Operation {
     fn from(proto_op: OperationProto) -> Self {
         Operation {
             client: proto_op.client,
+            dry_run: proto_op.dry_run,
         }
     }
 }
 
 impl From<Operation> for OperationProto {
     fn from(op: Operation) -> Self {
-        OperationProto { client: op.client }
+        OperationProto {
+            client: op.client,
+            dry_run: op.dry_run,
+        }
+    }
+}
+
+impl TryFrom<KeyDeletionFailureProto> for KeyDeletionFailure {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_failure: KeyDeletionFailureProto) -> std::result::Result<Self, Self::Error> {
+        let status: u16 = proto_failure.status.try_into()?;
+        Ok(KeyDeletionFailure {
+            key: proto_failure
+                .key
+                .ok_or_else(|| {
+                    error!("The key field of DeleteClient::KeyDeletionFailure message is not set (mandatory field).");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+            status: status.try_into()?,
+        })
     }
 }
 
-impl From<ResultProto> for Result {
-    fn from(_proto_op: ResultProto) -> Self {
-        Result {}
+impl TryFrom<KeyDeletionFailure> for KeyDeletionFailureProto {
+    type Error = ResponseStatus;
+
+    fn try_from(failure: KeyDeletionFailure) -> std::result::Result<Self, Self::Error> {
+        Ok(KeyDeletionFailureProto {
+            key: Some(failure.key.try_into()?),
+            status: failure.status as u32,
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            deleted_keys: proto_result
+                .deleted_keys
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<std::result::Result<_, _>>()?,
+            failures: proto_result
+                .failures
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<std::result::Result<_, _>>()?,
+        })
     }
 }
 
-impl From<Result> for ResultProto {
-    fn from(_op: Result) -> Self {
-        ResultProto {}
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            deleted_keys: result
+                .deleted_keys
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<std::result::Result<_, _>>()?,
+            failures: result
+                .failures
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<std::result::Result<_, _>>()?,
+        })
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::super::generated_ops::delete_client::Operation as OperationProto;
-    use crate::operations::delete_client::Operation;
+    use super::super::generated_ops::delete_client::{
+        KeyDeletionFailure as KeyDeletionFailureProto, Operation as OperationProto,
+        Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::delete_client::{KeyDeletionFailure, Operation, Result};
+    use crate::operations::list_keys::KeyInfo;
+    use crate::operations::psa_algorithm::{Algorithm, Cipher};
+    use crate::operations::psa_key_attributes::{Attributes, Lifetime, Policy, Type, UsageFlags};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{response::ResponseBody, Opcode, ProviderId, ResponseStatus};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_key(name: &str) -> KeyInfo {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_encrypt();
+        KeyInfo {
+            provider_id: ProviderId::MbedCrypto,
+            name: String::from(name),
+            attributes: Attributes {
+                lifetime: Lifetime::Persistent,
+                key_type: Type::Aes,
+                bits: 128,
+                policy: Policy {
+                    usage_flags,
+                    permitted_algorithms: Algorithm::Cipher(Cipher::Ctr),
+                },
+            },
+            creation_time: None,
+            origin: None,
+        }
+    }
+
+    fn get_result() -> Result {
+        Result {
+            deleted_keys: vec![get_key("key 1")],
+            failures: vec![KeyDeletionFailure {
+                key: get_key("key 2"),
+                status: ResponseStatus::PsaErrorStorageFailure,
+            }],
+        }
+    }
 
     #[test]
     fn proto_to_resp() {
         let proto = OperationProto {
             client: String::from("toto"),
+            dry_run: true,
         };
 
         let resp: Operation = proto.into();
 
         assert_eq!(resp.client, String::from("toto"));
+        assert!(resp.dry_run);
     }
 
     #[test]
     fn resp_to_proto() {
         let resp: Operation = Operation {
             client: String::from("toto"),
+            dry_run: false,
         };
 
         let proto: OperationProto = resp.into();
 
         assert_eq!(proto.client, String::from("toto"));
+        assert!(!proto.dry_run);
+    }
+
+    #[test]
+    fn result_round_trip() {
+        let proto: ResultProto = get_result().try_into().expect("Failed conversion");
+        assert_eq!(proto.deleted_keys.len(), 1);
+        assert_eq!(proto.failures.len(), 1);
+        assert_eq!(
+            proto.failures[0].status,
+            ResponseStatus::PsaErrorStorageFailure as u32
+        );
+
+        let result: Result = proto.try_into().expect("Failed conversion");
+        assert_eq!(result, get_result());
+    }
+
+    #[test]
+    fn invalid_failure_proto() {
+        let proto: ResultProto = get_result().try_into().expect("Failed conversion");
+
+        let mut missing_key = proto.clone();
+        missing_key.failures[0].key = None;
+        let result: std::result::Result<Result, ResponseStatus> = missing_key.try_into();
+        assert_eq!(result.unwrap_err(), ResponseStatus::InvalidEncoding);
+
+        let mut invalid_status = proto;
+        invalid_status.failures = vec![KeyDeletionFailureProto {
+            status: 0xffff,
+            ..invalid_status.failures[0].clone()
+        }];
+        let result: std::result::Result<Result, ResponseStatus> = invalid_status.try_into();
+        assert_eq!(result.unwrap_err(), ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn delete_client_res_e2e() {
+        let body = CONVERTER
+            .result_to_body(NativeResult::DeleteClient(get_result()))
+            .expect("Failed to convert to body");
+
+        match CONVERTER
+            .body_to_result(body, Opcode::DeleteClient)
+            .expect("Failed to convert to result")
+        {
+            NativeResult::DeleteClient(result) => assert_eq!(result, get_result()),
+            _ => panic!("Expected a DeleteClient result"),
+        }
+    }
+
+    #[test]
+    fn delete_client_op_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::DeleteClient(Operation {
+                client: String::from("toto"),
+                dry_run: true,
+            }))
+            .expect("Failed to convert to body");
+
+        let _ = CONVERTER
+            .body_to_operation(body, Opcode::DeleteClient)
+            .expect("Failed to convert to operation");
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::DeleteClient)
+            .is_err());
     }
 }
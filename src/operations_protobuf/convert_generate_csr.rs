@@ -0,0 +1,308 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::generate_csr::{
+    name_attribute as name_attribute_proto, subject_alt_name as subject_alt_name_proto,
+    NameAttribute as NameAttributeProto, Operation as OperationProto, Result as ResultProto,
+    SubjectAltName as SubjectAltNameProto,
+};
+use crate::operations::generate_csr::{NameAttribute, Operation, Result, SubjectAltName};
+use crate::requests::ResponseStatus;
+use log::error;
+use std::convert::{TryFrom, TryInto};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+impl TryFrom<NameAttributeProto> for NameAttribute {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_attribute: NameAttributeProto) -> std::result::Result<Self, Self::Error> {
+        match proto_attribute.attribute {
+            Some(name_attribute_proto::Attribute::CommonName(value)) => {
+                Ok(NameAttribute::CommonName(value))
+            }
+            Some(name_attribute_proto::Attribute::Organization(value)) => {
+                Ok(NameAttribute::Organization(value))
+            }
+            Some(name_attribute_proto::Attribute::OrganizationalUnit(value)) => {
+                Ok(NameAttribute::OrganizationalUnit(value))
+            }
+            Some(name_attribute_proto::Attribute::Country(value)) => {
+                Ok(NameAttribute::Country(value))
+            }
+            Some(name_attribute_proto::Attribute::StateOrProvince(value)) => {
+                Ok(NameAttribute::StateOrProvince(value))
+            }
+            Some(name_attribute_proto::Attribute::Locality(value)) => {
+                Ok(NameAttribute::Locality(value))
+            }
+            None => {
+                error!("The attribute field of GenerateCsr::NameAttribute message is not set (mandatory field).");
+                Err(ResponseStatus::InvalidEncoding)
+            }
+        }
+    }
+}
+
+impl From<NameAttribute> for NameAttributeProto {
+    fn from(attribute: NameAttribute) -> Self {
+        let attribute = match attribute {
+            NameAttribute::CommonName(value) => name_attribute_proto::Attribute::CommonName(value),
+            NameAttribute::Organization(value) => {
+                name_attribute_proto::Attribute::Organization(value)
+            }
+            NameAttribute::OrganizationalUnit(value) => {
+                name_attribute_proto::Attribute::OrganizationalUnit(value)
+            }
+            NameAttribute::Country(value) => name_attribute_proto::Attribute::Country(value),
+            NameAttribute::StateOrProvince(value) => {
+                name_attribute_proto::Attribute::StateOrProvince(value)
+            }
+            NameAttribute::Locality(value) => name_attribute_proto::Attribute::Locality(value),
+        };
+        NameAttributeProto {
+            attribute: Some(attribute),
+        }
+    }
+}
+
+impl TryFrom<SubjectAltNameProto> for SubjectAltName {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_name: SubjectAltNameProto) -> std::result::Result<Self, Self::Error> {
+        match proto_name.name {
+            Some(subject_alt_name_proto::Name::DnsName(name)) => Ok(SubjectAltName::DnsName(name)),
+            Some(subject_alt_name_proto::Name::IpAddress(address)) => {
+                let address = if let Ok(octets) = <[u8; 4]>::try_from(address.as_slice()) {
+                    IpAddr::V4(Ipv4Addr::from(octets))
+                } else if let Ok(octets) = <[u8; 16]>::try_from(address.as_slice()) {
+                    IpAddr::V6(Ipv6Addr::from(octets))
+                } else {
+                    error!(
+                        "IP addresses must be 4 or 16 bytes long, found {} bytes.",
+                        address.len()
+                    );
+                    return Err(ResponseStatus::InvalidEncoding);
+                };
+                Ok(SubjectAltName::IpAddress(address))
+            }
+            Some(subject_alt_name_proto::Name::Email(email)) => Ok(SubjectAltName::Email(email)),
+            Some(subject_alt_name_proto::Name::Uri(uri)) => Ok(SubjectAltName::Uri(uri)),
+            None => {
+                error!("The name field of GenerateCsr::SubjectAltName message is not set (mandatory field).");
+                Err(ResponseStatus::InvalidEncoding)
+            }
+        }
+    }
+}
+
+impl From<SubjectAltName> for SubjectAltNameProto {
+    fn from(name: SubjectAltName) -> Self {
+        let name = match name {
+            SubjectAltName::DnsName(name) => subject_alt_name_proto::Name::DnsName(name),
+            SubjectAltName::IpAddress(IpAddr::V4(address)) => {
+                subject_alt_name_proto::Name::IpAddress(address.octets().to_vec())
+            }
+            SubjectAltName::IpAddress(IpAddr::V6(address)) => {
+                subject_alt_name_proto::Name::IpAddress(address.octets().to_vec())
+            }
+            SubjectAltName::Email(email) => subject_alt_name_proto::Name::Email(email),
+            SubjectAltName::Uri(uri) => subject_alt_name_proto::Name::Uri(uri),
+        };
+        SubjectAltNameProto { name: Some(name) }
+    }
+}
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        let mut subject = Vec::new();
+        for attribute in proto_op.subject {
+            subject.push(attribute.try_into()?);
+        }
+        let mut subject_alt_names = Vec::new();
+        for name in proto_op.subject_alt_names {
+            subject_alt_names.push(name.try_into()?);
+        }
+
+        Ok(Operation {
+            key_name: proto_op.key_name,
+            subject,
+            subject_alt_names,
+            alg: proto_op
+                .alg
+                .ok_or_else(|| {
+                    error!("The alg field of GenerateCsr::Operation message is not set (mandatory field).");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            key_name: op.key_name,
+            subject: op.subject.into_iter().map(Into::into).collect(),
+            subject_alt_names: op.subject_alt_names.into_iter().map(Into::into).collect(),
+            alg: Some(op.alg.try_into()?),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            csr: proto_result.csr,
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto { csr: result.csr })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::generate_csr::{
+        subject_alt_name as subject_alt_name_proto, NameAttribute as NameAttributeProto,
+        Operation as OperationProto, Result as ResultProto, SubjectAltName as SubjectAltNameProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::generate_csr::{NameAttribute, Operation, Result, SubjectAltName};
+    use crate::operations::psa_algorithm::{AsymmetricSignature, Hash, SignHash};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode, ResponseStatus};
+    use std::convert::TryInto;
+    use std::net::{IpAddr, Ipv6Addr};
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    const ALG: AsymmetricSignature = AsymmetricSignature::RsaPss {
+        hash_alg: SignHash::Specific(Hash::Sha256),
+    };
+
+    fn get_op() -> Operation {
+        Operation {
+            key_name: String::from("some key"),
+            subject: vec![
+                NameAttribute::Country(String::from("GB")),
+                NameAttribute::Organization(String::from("Parsec")),
+                NameAttribute::OrganizationalUnit(String::from("Devices")),
+                NameAttribute::StateOrProvince(String::from("Cambridgeshire")),
+                NameAttribute::Locality(String::from("Cambridge")),
+                NameAttribute::CommonName(String::from("device.example.com")),
+            ],
+            subject_alt_names: vec![
+                SubjectAltName::DnsName(String::from("device.example.com")),
+                SubjectAltName::IpAddress(IpAddr::from([10, 0, 0, 1])),
+                SubjectAltName::IpAddress(IpAddr::V6(Ipv6Addr::LOCALHOST)),
+                SubjectAltName::Email(String::from("admin@example.com")),
+                SubjectAltName::Uri(String::from("urn:example:device")),
+            ],
+            alg: ALG,
+        }
+    }
+
+    #[test]
+    fn generate_csr_op_round_trip() {
+        let proto: OperationProto = get_op().try_into().expect("Failed conversion");
+        assert_eq!(proto.key_name, "some key");
+        assert_eq!(proto.subject.len(), 6);
+        assert_eq!(proto.subject_alt_names.len(), 5);
+        assert_eq!(
+            proto.subject_alt_names[1].name,
+            Some(subject_alt_name_proto::Name::IpAddress(vec![10, 0, 0, 1]))
+        );
+
+        let op: Operation = proto.try_into().expect("Failed conversion");
+        assert_eq!(op.key_name, "some key");
+        assert_eq!(op.subject, get_op().subject);
+        assert_eq!(op.subject_alt_names, get_op().subject_alt_names);
+        assert_eq!(op.alg, ALG);
+    }
+
+    #[test]
+    fn generate_csr_proto_invalid_fields() {
+        let proto: OperationProto = get_op().try_into().expect("Failed conversion");
+
+        let mut missing_alg = proto.clone();
+        missing_alg.alg = None;
+        let op: std::result::Result<Operation, ResponseStatus> = missing_alg.try_into();
+        assert_eq!(op.unwrap_err(), ResponseStatus::InvalidEncoding);
+
+        let mut missing_attribute = proto.clone();
+        missing_attribute.subject = vec![NameAttributeProto { attribute: None }];
+        let op: std::result::Result<Operation, ResponseStatus> = missing_attribute.try_into();
+        assert_eq!(op.unwrap_err(), ResponseStatus::InvalidEncoding);
+
+        let mut invalid_address = proto;
+        invalid_address.subject_alt_names = vec![SubjectAltNameProto {
+            name: Some(subject_alt_name_proto::Name::IpAddress(vec![10, 0, 0])),
+        }];
+        let op: std::result::Result<Operation, ResponseStatus> = invalid_address.try_into();
+        assert_eq!(op.unwrap_err(), ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn generate_csr_res_round_trip() {
+        let proto: ResultProto = Result {
+            csr: vec![0x30, 0x82, 0x01, 0x0a],
+        }
+        .try_into()
+        .expect("Failed conversion");
+        assert_eq!(proto.csr, vec![0x30, 0x82, 0x01, 0x0a]);
+
+        let result: Result = proto.try_into().expect("Failed conversion");
+        assert_eq!(result.csr, vec![0x30, 0x82, 0x01, 0x0a]);
+    }
+
+    #[test]
+    fn generate_csr_op_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::GenerateCsr(get_op()))
+            .expect("Failed to convert to body");
+
+        let _ = CONVERTER
+            .body_to_operation(body, Opcode::GenerateCsr)
+            .expect("Failed to convert to operation");
+    }
+
+    #[test]
+    fn generate_csr_res_e2e() {
+        let body = CONVERTER
+            .result_to_body(NativeResult::GenerateCsr(Result {
+                csr: vec![0x30, 0x82, 0x01, 0x0a],
+            }))
+            .expect("Failed to convert to body");
+
+        assert!(CONVERTER.body_to_result(body, Opcode::GenerateCsr).is_ok());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::GenerateCsr)
+            .is_err());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::GenerateCsr)
+            .is_err());
+    }
+}
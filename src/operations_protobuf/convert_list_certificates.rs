@@ -0,0 +1,97 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::list_certificates::{Operation as OperationProto, Result as ResultProto};
+use crate::operations::list_certificates::{Operation, Result};
+use crate::requests::ResponseStatus;
+use std::convert::TryFrom;
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(_proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {})
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(_op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(Default::default())
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            key_names: proto_result.key_names,
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            key_names: result.key_names,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    // Operation <-> Proto conversions are not tested since they're too simple
+    use super::super::generated_ops::list_certificates::Result as ResultProto;
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::list_certificates::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{response::ResponseBody, Opcode};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn list_certificates_res_round_trip() {
+        let proto: ResultProto = Result {
+            key_names: vec![String::from("key 1"), String::from("key 2")],
+        }
+        .try_into()
+        .expect("Failed conversion");
+        assert_eq!(proto.key_names, vec!["key 1", "key 2"]);
+
+        let result: Result = proto.try_into().expect("Failed conversion");
+        assert_eq!(result.key_names, vec!["key 1", "key 2"]);
+    }
+
+    #[test]
+    fn list_certificates_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::ListCertificates(Operation {}))
+            .expect("Failed to convert to body");
+        assert!(body.is_empty());
+        let _ = CONVERTER
+            .body_to_operation(body, Opcode::ListCertificates)
+            .expect("Failed to convert to operation");
+
+        let body = CONVERTER
+            .result_to_body(NativeResult::ListCertificates(Result {
+                key_names: vec![String::from("key 1")],
+            }))
+            .expect("Failed to convert to body");
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::ListCertificates)
+            .is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::ListCertificates)
+            .is_err());
+    }
+}
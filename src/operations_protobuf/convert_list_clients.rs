@@ -1,40 +1,137 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use super::generated_ops::list_clients::{Operation as OperationProto, Result as ResultProto};
-use crate::operations::list_clients::{Operation, Result};
+use super::convert_list_keys::{string_to_option, u32_to_provider_id};
+use super::generated_ops::list_clients::{
+    ClientInfo as ClientInfoProto, Operation as OperationProto,
+    ProviderKeyCount as ProviderKeyCountProto, Result as ResultProto,
+};
+use crate::operations::list_clients::{ClientInfo, Operation, Result};
+use crate::requests::ResponseStatus;
+use std::collections::HashMap;
+use std::convert::{TryFrom, TryInto};
 
 impl From<OperationProto> for Operation {
-    fn from(_proto_op: OperationProto) -> Self {
-        Operation {}
+    fn from(proto_op: OperationProto) -> Self {
+        Operation {
+            page_token: string_to_option(proto_op.page_token),
+            limit: match proto_op.limit {
+                0 => None,
+                limit => Some(limit),
+            },
+        }
     }
 }
 
 impl From<Operation> for OperationProto {
-    fn from(_op: Operation) -> Self {
-        Default::default()
+    fn from(op: Operation) -> Self {
+        OperationProto {
+            page_token: op.page_token.unwrap_or_default(),
+            limit: op.limit.unwrap_or(0),
+        }
     }
 }
 
-impl From<ResultProto> for Result {
-    fn from(proto_op: ResultProto) -> Self {
-        Result {
-            clients: proto_op.clients,
+impl TryFrom<ClientInfoProto> for ClientInfo {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_info: ClientInfoProto) -> std::result::Result<Self, Self::Error> {
+        let mut keys_per_provider = HashMap::new();
+        for count in proto_info.keys_per_provider {
+            let _ =
+                keys_per_provider.insert(u32_to_provider_id(count.provider_id)?, count.key_count);
         }
+
+        Ok(ClientInfo {
+            name: proto_info.name,
+            keys_per_provider,
+            last_seen: match proto_info.last_seen {
+                0 => None,
+                last_seen => Some(last_seen),
+            },
+        })
+    }
+}
+
+impl From<ClientInfo> for ClientInfoProto {
+    fn from(info: ClientInfo) -> Self {
+        ClientInfoProto {
+            name: info.name,
+            keys_per_provider: info
+                .keys_per_provider
+                .into_iter()
+                .map(|(provider_id, key_count)| ProviderKeyCountProto {
+                    provider_id: provider_id as u32,
+                    key_count,
+                })
+                .collect(),
+            last_seen: info.last_seen.unwrap_or(0),
+        }
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: ResultProto) -> std::result::Result<Self, Self::Error> {
+        // Services that do not send the client information only fill in the names.
+        let clients = if proto_op.client_infos.is_empty() {
+            proto_op
+                .clients
+                .into_iter()
+                .map(|name| ClientInfo {
+                    name,
+                    ..Default::default()
+                })
+                .collect()
+        } else {
+            let mut clients = Vec::new();
+            for info in proto_op.client_infos {
+                clients.push(info.try_into()?);
+            }
+            clients
+        };
+
+        Ok(Result {
+            clients,
+            next_page_token: string_to_option(proto_op.next_page_token),
+        })
     }
 }
 
 impl From<Result> for ResultProto {
     fn from(op: Result) -> Self {
         ResultProto {
-            clients: op.clients,
+            clients: op.clients.iter().map(|info| info.name.clone()).collect(),
+            client_infos: op.clients.into_iter().map(Into::into).collect(),
+            next_page_token: op.next_page_token.unwrap_or_default(),
         }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::super::generated_ops::list_clients::Result as ResultProto;
-    use crate::operations::list_clients::Result;
+    use super::super::generated_ops::list_clients::{
+        ClientInfo as ClientInfoProto, Operation as OperationProto,
+        ProviderKeyCount as ProviderKeyCountProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::list_clients::{ClientInfo, Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{Opcode, ProviderId, ResponseStatus};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_info() -> ClientInfo {
+        let mut info = ClientInfo {
+            name: String::from("toto"),
+            keys_per_provider: Default::default(),
+            last_seen: Some(1_600_000_000),
+        };
+        let _ = info.keys_per_provider.insert(ProviderId::MbedCrypto, 2);
+        let _ = info.keys_per_provider.insert(ProviderId::Tpm, 1);
+        info
+    }
 
     #[test]
     fn proto_to_resp() {
@@ -42,21 +139,114 @@ mod test {
 
         proto.clients.push(String::from("toto"));
 
-        let resp: Result = proto.into();
+        let resp: Result = proto.try_into().unwrap();
 
         assert_eq!(resp.clients.len(), 1);
-        assert_eq!(resp.clients[0], String::from("toto"));
+        assert_eq!(resp.clients[0].name, String::from("toto"));
+        assert!(resp.clients[0].keys_per_provider.is_empty());
+        assert!(resp.clients[0].last_seen.is_none());
+        assert!(resp.next_page_token.is_none());
     }
 
     #[test]
     fn resp_to_proto() {
         let resp: Result = Result {
-            clients: vec![String::from("toto")],
+            clients: vec![get_info()],
+            next_page_token: Some(String::from("next")),
         };
 
         let proto: ResultProto = resp.into();
 
         assert_eq!(proto.clients.len(), 1);
         assert_eq!(proto.clients[0], String::from("toto"));
+        assert_eq!(proto.client_infos.len(), 1);
+        assert_eq!(proto.client_infos[0].name, String::from("toto"));
+        assert_eq!(proto.client_infos[0].keys_per_provider.len(), 2);
+        assert_eq!(proto.client_infos[0].last_seen, 1_600_000_000);
+        assert_eq!(proto.next_page_token, "next");
+    }
+
+    #[test]
+    fn resp_round_trip() {
+        let proto: ResultProto = Result {
+            clients: vec![get_info()],
+            next_page_token: None,
+        }
+        .into();
+        let resp: Result = proto.try_into().unwrap();
+
+        assert_eq!(resp.clients, vec![get_info()]);
+        assert_eq!(resp.clients[0].key_count(), 3);
+        assert!(resp.next_page_token.is_none());
+    }
+
+    #[test]
+    fn proto_to_resp_invalid_provider() {
+        let proto = ResultProto {
+            clients: vec![String::from("toto")],
+            client_infos: vec![ClientInfoProto {
+                name: String::from("toto"),
+                keys_per_provider: vec![ProviderKeyCountProto {
+                    provider_id: 0xff,
+                    key_count: 1,
+                }],
+                last_seen: 0,
+            }],
+            next_page_token: String::new(),
+        };
+
+        let resp: std::result::Result<Result, ResponseStatus> = proto.try_into();
+        assert_eq!(resp.unwrap_err(), ResponseStatus::ProviderDoesNotExist);
+    }
+
+    #[test]
+    fn op_round_trip() {
+        let op: Operation = OperationProto::default().into();
+        assert_eq!(op, Operation::default());
+
+        let op = Operation {
+            page_token: Some(String::from("token")),
+            limit: Some(10),
+        };
+        let proto: OperationProto = op.clone().into();
+        assert_eq!(proto.page_token, "token");
+        assert_eq!(proto.limit, 10);
+        let new_op: Operation = proto.into();
+        assert_eq!(new_op, op);
+    }
+
+    #[test]
+    fn list_clients_e2e() {
+        let op = Operation {
+            page_token: None,
+            limit: Some(1),
+        };
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::ListClients(op.clone()))
+            .expect("Failed to convert to body");
+        match CONVERTER
+            .body_to_operation(body, Opcode::ListClients)
+            .expect("Failed to convert to operation")
+        {
+            NativeOperation::ListClients(new_op) => assert_eq!(new_op, op),
+            _ => panic!("Expected a ListClients operation"),
+        }
+
+        let body = CONVERTER
+            .result_to_body(NativeResult::ListClients(Result {
+                clients: vec![get_info()],
+                next_page_token: Some(String::from("next")),
+            }))
+            .expect("Failed to convert to body");
+        match CONVERTER
+            .body_to_result(body, Opcode::ListClients)
+            .expect("Failed to convert to result")
+        {
+            NativeResult::ListClients(result) => {
+                assert_eq!(result.clients, vec![get_info()]);
+                assert_eq!(result.next_page_token, Some(String::from("next")));
+            }
+            _ => panic!("Expected a ListClients result"),
+        }
     }
 }
@@ -1,27 +1,114 @@
 // Copyright 2020 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use super::generated_ops::list_keys::{
-    KeyInfo as KeyInfoProto, Operation as OperationProto, Result as ResultProto,
+    KeyInfo as KeyInfoProto, KeyLifetime as KeyLifetimeProto, KeyOrigin as KeyOriginProto,
+    Operation as OperationProto, ProviderFilter as ProviderFilterProto, Result as ResultProto,
 };
-use crate::operations::list_keys::{KeyInfo, Operation, Result};
+use crate::operations::list_keys::{KeyInfo, KeyOrigin, Operation, Result};
+use crate::operations::psa_key_attributes::{Attributes, Lifetime};
 use crate::requests::{ProviderId, ResponseStatus};
 use log::error;
 use num::FromPrimitive;
 use std::convert::{TryFrom, TryInto};
 
+pub(super) fn u32_to_provider_id(
+    provider_id: u32,
+) -> std::result::Result<ProviderId, ResponseStatus> {
+    match FromPrimitive::from_u32(provider_id) {
+        Some(id) => Ok(id),
+        None => Err(ResponseStatus::ProviderDoesNotExist),
+    }
+}
+
+// Optional strings are encoded as empty strings when absent.
+pub(super) fn string_to_option(string: String) -> Option<String> {
+    if string.is_empty() {
+        None
+    } else {
+        Some(string)
+    }
+}
+
 impl TryFrom<OperationProto> for Operation {
     type Error = ResponseStatus;
 
-    fn try_from(_proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
-        Ok(Operation {})
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            provider_id: proto_op
+                .provider
+                .map(|provider| u32_to_provider_id(provider.provider_id))
+                .transpose()?,
+            key_type: proto_op.key_type.map(TryInto::try_into).transpose()?,
+            usage_flags: proto_op.usage_flags.map(TryInto::try_into).transpose()?,
+            name_prefix: string_to_option(proto_op.name_prefix),
+            page_token: string_to_option(proto_op.page_token),
+            limit: match proto_op.limit {
+                0 => None,
+                limit => Some(limit),
+            },
+        })
     }
 }
 
 impl TryFrom<Operation> for OperationProto {
     type Error = ResponseStatus;
 
-    fn try_from(_op: Operation) -> std::result::Result<Self, Self::Error> {
-        Ok(Default::default())
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            provider: op.provider_id.map(|provider_id| ProviderFilterProto {
+                provider_id: provider_id as u32,
+            }),
+            key_type: op.key_type.map(TryInto::try_into).transpose()?,
+            usage_flags: op.usage_flags.map(TryInto::try_into).transpose()?,
+            name_prefix: op.name_prefix.unwrap_or_default(),
+            page_token: op.page_token.unwrap_or_default(),
+            limit: op.limit.unwrap_or(0),
+        })
+    }
+}
+
+// Lifetimes are encoded with their PSA Crypto API values.
+fn u32_to_lifetime(lifetime: u32) -> Lifetime {
+    match lifetime {
+        0 => Lifetime::Volatile,
+        1 => Lifetime::Persistent,
+        lifetime => Lifetime::Custom(lifetime),
+    }
+}
+
+fn lifetime_to_u32(lifetime: Lifetime) -> u32 {
+    match lifetime {
+        Lifetime::Volatile => 0,
+        Lifetime::Persistent => 1,
+        Lifetime::Custom(lifetime) => lifetime,
+    }
+}
+
+// The origin of keys is optional and encoded as `KeyOrigin::None` when unknown.
+fn i32_to_origin(origin_val: i32) -> std::result::Result<Option<KeyOrigin>, ResponseStatus> {
+    match KeyOriginProto::from_i32(origin_val) {
+        Some(KeyOriginProto::None) => Ok(None),
+        Some(KeyOriginProto::Generated) => Ok(Some(KeyOrigin::Generated)),
+        Some(KeyOriginProto::Imported) => Ok(Some(KeyOrigin::Imported)),
+        Some(KeyOriginProto::Derived) => Ok(Some(KeyOrigin::Derived)),
+        Some(KeyOriginProto::Copied) => Ok(Some(KeyOrigin::Copied)),
+        None => {
+            error!(
+                "Value {} not supported as a key origin encoding.",
+                origin_val
+            );
+            Err(ResponseStatus::InvalidEncoding)
+        }
+    }
+}
+
+fn origin_to_i32(origin: Option<KeyOrigin>) -> i32 {
+    match origin {
+        None => KeyOriginProto::None.into(),
+        Some(KeyOrigin::Generated) => KeyOriginProto::Generated.into(),
+        Some(KeyOrigin::Imported) => KeyOriginProto::Imported.into(),
+        Some(KeyOrigin::Derived) => KeyOriginProto::Derived.into(),
+        Some(KeyOrigin::Copied) => KeyOriginProto::Copied.into(),
     }
 }
 
@@ -29,23 +116,29 @@ impl TryFrom<KeyInfoProto> for KeyInfo {
     type Error = ResponseStatus;
 
     fn try_from(proto_info: KeyInfoProto) -> std::result::Result<Self, Self::Error> {
-        let id: ProviderId = match FromPrimitive::from_u32(proto_info.provider_id) {
-            Some(id) => id,
-            None => return Err(ResponseStatus::ProviderDoesNotExist),
-        };
+        let id = u32_to_provider_id(proto_info.provider_id)?;
 
-        let attributes = proto_info
+        let mut attributes: Attributes = proto_info
             .attributes
             .ok_or_else(|| {
                 error!("The attributes field of KeyInfo protobuf message is not set (mandatory field).");
                 ResponseStatus::InvalidEncoding
             })?
             .try_into()?;
+        // The key attributes message does not carry the lifetime of the key.
+        if let Some(lifetime) = proto_info.lifetime {
+            attributes.lifetime = u32_to_lifetime(lifetime.value);
+        }
 
         Ok(KeyInfo {
             provider_id: id,
             name: proto_info.name,
             attributes,
+            creation_time: match proto_info.creation_time {
+                0 => None,
+                creation_time => Some(creation_time),
+            },
+            origin: i32_to_origin(proto_info.origin)?,
         })
     }
 }
@@ -57,7 +150,12 @@ impl TryFrom<KeyInfo> for KeyInfoProto {
         Ok(KeyInfoProto {
             provider_id: info.provider_id as u32,
             name: info.name,
+            lifetime: Some(KeyLifetimeProto {
+                value: lifetime_to_u32(info.attributes.lifetime),
+            }),
             attributes: Some(info.attributes.try_into()?),
+            creation_time: info.creation_time.unwrap_or(0),
+            origin: origin_to_i32(info.origin),
         })
     }
 }
@@ -71,7 +169,10 @@ impl TryFrom<ResultProto> for Result {
             keys.push(key.try_into()?);
         }
 
-        Ok(Result { keys })
+        Ok(Result {
+            keys,
+            next_page_token: string_to_option(proto_op.next_page_token),
+        })
     }
 }
 
@@ -84,21 +185,28 @@ impl TryFrom<Result> for ResultProto {
             keys.push(key.try_into()?);
         }
 
-        Ok(ResultProto { keys })
+        Ok(ResultProto {
+            keys,
+            next_page_token: op.next_page_token.unwrap_or_default(),
+        })
     }
 }
 
 #[cfg(test)]
 mod test {
-    // Operation <-> Proto conversions are not tested since they're too simple
-    use super::super::generated_ops::list_keys::{KeyInfo as KeyInfoProto, Result as ResultProto};
+    use super::super::generated_ops::list_keys::{
+        KeyInfo as KeyInfoProto, KeyOrigin as KeyOriginProto, Operation as OperationProto,
+        ProviderFilter as ProviderFilterProto, Result as ResultProto,
+    };
     use super::super::generated_ops::psa_key_attributes::KeyAttributes as KeyAttributesProto;
     use super::super::{Convert, ProtobufConverter};
-    use crate::operations::list_keys::{KeyInfo, Operation, Result};
+    use crate::operations::list_keys::{KeyInfo, KeyOrigin, Operation, Result};
     use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, Hash};
     use crate::operations::psa_key_attributes::{self, Attributes, Lifetime, Policy, UsageFlags};
     use crate::operations::{NativeOperation, NativeResult};
-    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode, ProviderId};
+    use crate::requests::{
+        request::RequestBody, response::ResponseBody, Opcode, ProviderId, ResponseStatus,
+    };
     use std::convert::TryInto;
 
     static CONVERTER: ProtobufConverter = ProtobufConverter {};
@@ -139,6 +247,7 @@ mod test {
             provider_id: ProviderId::MbedCrypto as u32,
             name: String::from("Some Key Name"),
             attributes: Some(key_attrs_proto),
+            ..Default::default()
         };
         proto.keys.push(key_info);
 
@@ -148,11 +257,16 @@ mod test {
         assert_eq!(resp.keys[0].name, "Some Key Name");
         assert_eq!(resp.keys[0].provider_id, ProviderId::MbedCrypto);
         assert_eq!(resp.keys[0].attributes, key_attrs);
+        assert!(resp.keys[0].creation_time.is_none());
+        assert!(resp.keys[0].origin.is_none());
     }
 
     #[test]
     fn resp_to_proto() {
-        let mut resp: Result = Result { keys: Vec::new() };
+        let mut resp: Result = Result {
+            keys: Vec::new(),
+            next_page_token: None,
+        };
         let mut usage_flags = UsageFlags::default();
         let _ = usage_flags
             .set_decrypt()
@@ -183,6 +297,8 @@ mod test {
             provider_id: ProviderId::MbedCrypto,
             name: String::from("Foo"),
             attributes: key_attributes,
+            creation_time: None,
+            origin: None,
         };
         resp.keys.push(key_info);
 
@@ -205,7 +321,7 @@ mod test {
 
     #[test]
     fn op_list_keys_from_native() {
-        let list_keys = Operation {};
+        let list_keys = Operation::default();
         let body = CONVERTER
             .operation_to_body(NativeOperation::ListKeys(list_keys))
             .expect("Failed to convert request");
@@ -214,7 +330,7 @@ mod test {
 
     #[test]
     fn op_list_keys_e2e() {
-        let list_keys = Operation {};
+        let list_keys = Operation::default();
         let req_body = CONVERTER
             .operation_to_body(NativeOperation::ListKeys(list_keys))
             .expect("Failed to convert request");
@@ -244,7 +360,10 @@ mod test {
 
     #[test]
     fn result_list_keys_from_native() {
-        let mut list_keys = Result { keys: Vec::new() };
+        let mut list_keys = Result {
+            keys: Vec::new(),
+            next_page_token: None,
+        };
         let mut usage_flags = UsageFlags::default();
         let _ = usage_flags
             .set_decrypt()
@@ -274,6 +393,8 @@ mod test {
                     ),
                 },
             },
+            creation_time: None,
+            origin: None,
         };
         list_keys.keys.push(key_info);
 
@@ -298,7 +419,10 @@ mod test {
             .set_sign_hash()
             .set_verify_hash()
             .set_derive();
-        let mut list_keys = Result { keys: Vec::new() };
+        let mut list_keys = Result {
+            keys: Vec::new(),
+            next_page_token: None,
+        };
         let key_info = KeyInfo {
             provider_id: ProviderId::MbedCrypto,
             name: String::from("Baz"),
@@ -315,6 +439,8 @@ mod test {
                     ),
                 },
             },
+            creation_time: None,
+            origin: None,
         };
         list_keys.keys.push(key_info);
 
@@ -343,4 +469,120 @@ mod test {
             .body_to_result(resp_body, Opcode::ListKeys)
             .is_err());
     }
+
+    #[test]
+    fn key_info_metadata_round_trip() {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_sign_hash();
+        let key_info = KeyInfo {
+            provider_id: ProviderId::Tpm,
+            name: String::from("Qux"),
+            attributes: Attributes {
+                lifetime: Lifetime::Volatile,
+                key_type: psa_key_attributes::Type::RsaKeyPair,
+                bits: 2048,
+                policy: Policy {
+                    usage_flags,
+                    permitted_algorithms: Algorithm::AsymmetricSignature(
+                        AsymmetricSignature::RsaPkcs1v15Sign {
+                            hash_alg: Hash::Sha256.into(),
+                        },
+                    ),
+                },
+            },
+            creation_time: Some(1_600_000_000),
+            origin: Some(KeyOrigin::Imported),
+        };
+
+        let proto: KeyInfoProto = key_info.clone().try_into().expect("Failed conversion");
+        assert_eq!(proto.creation_time, 1_600_000_000);
+        assert_eq!(proto.origin, KeyOriginProto::Imported as i32);
+        assert_eq!(
+            proto.lifetime.as_ref().map(|lifetime| lifetime.value),
+            Some(0)
+        );
+
+        let native: KeyInfo = proto.try_into().expect("Failed conversion");
+        assert_eq!(native, key_info);
+    }
+
+    #[test]
+    fn key_info_invalid_origin() {
+        let proto = KeyInfoProto {
+            provider_id: ProviderId::MbedCrypto as u32,
+            name: String::from("Quux"),
+            attributes: Some(
+                Attributes {
+                    lifetime: Lifetime::Persistent,
+                    key_type: psa_key_attributes::Type::Aes,
+                    bits: 128,
+                    policy: Policy {
+                        usage_flags: UsageFlags::default(),
+                        permitted_algorithms: Algorithm::None,
+                    },
+                }
+                .try_into()
+                .expect("Failed conversion"),
+            ),
+            origin: 42,
+            ..Default::default()
+        };
+
+        let native: std::result::Result<KeyInfo, ResponseStatus> = proto.try_into();
+        assert_eq!(native.unwrap_err(), ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn op_list_keys_filters_round_trip() {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_sign_hash();
+        let op = Operation {
+            provider_id: Some(ProviderId::Core),
+            key_type: Some(psa_key_attributes::Type::RsaKeyPair),
+            usage_flags: Some(usage_flags),
+            name_prefix: Some(String::from("tenant-a/")),
+            page_token: Some(String::from("token")),
+            limit: Some(100),
+        };
+
+        let proto: OperationProto = op.clone().try_into().expect("Failed conversion");
+        assert_eq!(
+            proto.provider.as_ref().map(|provider| provider.provider_id),
+            Some(ProviderId::Core as u32)
+        );
+        let native: Operation = proto.try_into().expect("Failed conversion");
+        assert_eq!(native, op);
+
+        let proto: OperationProto = Operation::default().try_into().expect("Failed conversion");
+        let native: Operation = proto.try_into().expect("Failed conversion");
+        assert_eq!(native, Operation::default());
+    }
+
+    #[test]
+    fn op_list_keys_unknown_provider() {
+        let proto = OperationProto {
+            provider: Some(ProviderFilterProto { provider_id: 0xFF }),
+            ..Default::default()
+        };
+        let op: std::result::Result<Operation, ResponseStatus> = proto.try_into();
+        assert_eq!(op.unwrap_err(), ResponseStatus::ProviderDoesNotExist);
+    }
+
+    #[test]
+    fn result_next_page_token() {
+        let proto = ResultProto {
+            keys: Vec::new(),
+            next_page_token: String::from("next"),
+        };
+        let result: Result = proto.try_into().expect("Failed conversion");
+        assert_eq!(result.next_page_token, Some(String::from("next")));
+
+        let proto: ResultProto = Result {
+            keys: Vec::new(),
+            next_page_token: None,
+        }
+        .try_into()
+        .expect("Failed conversion");
+        assert!(proto.next_page_token.is_empty());
+    }
 }
@@ -1,12 +1,14 @@
 // Copyright 2019 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use super::generated_ops::list_opcodes::{Operation as OperationProto, Result as ResultProto};
-use crate::operations::list_opcodes::{Operation, Result};
+use super::generated_ops::list_opcodes::{
+    OpcodeInfo as OpcodeInfoProto, Operation as OperationProto, Result as ResultProto,
+};
+use crate::operations::list_opcodes::{OpcodeInfo, Operation, Result};
 use crate::requests::{Opcode, ResponseStatus};
 use log::error;
 use num::FromPrimitive;
 use std::collections::HashSet;
-use std::convert::TryFrom;
+use std::convert::{TryFrom, TryInto};
 
 impl TryFrom<OperationProto> for Operation {
     type Error = ResponseStatus;
@@ -32,6 +34,47 @@ impl TryFrom<Operation> for OperationProto {
     }
 }
 
+impl TryFrom<OpcodeInfoProto> for OpcodeInfo {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_info: OpcodeInfoProto) -> std::result::Result<Self, Self::Error> {
+        let opcode = match FromPrimitive::from_u32(proto_info.opcode) {
+            Some(code) => code,
+            None => return Err(ResponseStatus::OpcodeDoesNotExist),
+        };
+        let mut supported_algorithms = Vec::new();
+        for alg in proto_info.supported_algorithms {
+            supported_algorithms.push(alg.try_into()?);
+        }
+
+        Ok(OpcodeInfo {
+            opcode,
+            max_input_size: match proto_info.max_input_size {
+                0 => None,
+                max_input_size => Some(max_input_size.try_into()?),
+            },
+            supported_algorithms,
+        })
+    }
+}
+
+impl TryFrom<OpcodeInfo> for OpcodeInfoProto {
+    type Error = ResponseStatus;
+
+    fn try_from(info: OpcodeInfo) -> std::result::Result<Self, Self::Error> {
+        let mut supported_algorithms = Vec::new();
+        for alg in info.supported_algorithms {
+            supported_algorithms.push(alg.try_into()?);
+        }
+
+        Ok(OpcodeInfoProto {
+            opcode: info.opcode as u32,
+            max_input_size: info.max_input_size.unwrap_or(0).try_into()?,
+            supported_algorithms,
+        })
+    }
+}
+
 impl TryFrom<ResultProto> for Result {
     type Error = ResponseStatus;
 
@@ -45,7 +88,15 @@ impl TryFrom<ResultProto> for Result {
             let _ = opcodes.insert(opcode);
         }
 
-        Ok(Result { opcodes })
+        let mut opcode_info = Vec::new();
+        for info in proto_op.opcode_info {
+            opcode_info.push(info.try_into()?);
+        }
+
+        Ok(Result {
+            opcodes,
+            opcode_info,
+        })
     }
 }
 
@@ -58,19 +109,33 @@ impl TryFrom<Result> for ResultProto {
             opcodes.push(opcode as u32);
         }
 
-        Ok(ResultProto { opcodes })
+        let mut opcode_info = Vec::new();
+        for info in op.opcode_info {
+            opcode_info.push(info.try_into()?);
+        }
+
+        Ok(ResultProto {
+            opcodes,
+            opcode_info,
+        })
     }
 }
 
 #[cfg(test)]
 mod test {
     // Operation <-> Proto conversions are not tested since they're too simple
-    use super::super::generated_ops::list_opcodes::Result as ResultProto;
+    use super::super::generated_ops::list_opcodes::{
+        OpcodeInfo as OpcodeInfoProto, Result as ResultProto,
+    };
     use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, Hash};
     use crate::operations::{
-        list_opcodes::Operation, list_opcodes::Result, NativeOperation, NativeResult,
+        list_opcodes::OpcodeInfo, list_opcodes::Operation, list_opcodes::Result, NativeOperation,
+        NativeResult,
+    };
+    use crate::requests::{
+        request::RequestBody, response::ResponseBody, Opcode, ProviderId, ResponseStatus,
     };
-    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode, ProviderId};
     use std::collections::HashSet;
     use std::convert::TryInto;
 
@@ -90,6 +155,7 @@ mod test {
     fn resp_to_proto() {
         let mut resp: Result = Result {
             opcodes: HashSet::new(),
+            opcode_info: Vec::new(),
         };
         let _ = resp.opcodes.insert(Opcode::Ping);
 
@@ -98,6 +164,44 @@ mod test {
         assert_eq!(proto.opcodes[0], 1);
     }
 
+    #[test]
+    fn opcode_info_round_trip() {
+        let info = OpcodeInfo {
+            opcode: Opcode::PsaSignHash,
+            max_input_size: Some(64),
+            supported_algorithms: vec![Algorithm::AsymmetricSignature(
+                AsymmetricSignature::Ecdsa {
+                    hash_alg: Hash::Sha256.into(),
+                },
+            )],
+        };
+        let resp = Result {
+            opcodes: [Opcode::PsaSignHash].iter().copied().collect(),
+            opcode_info: vec![info.clone()],
+        };
+
+        let proto: ResultProto = resp.try_into().unwrap();
+        assert_eq!(proto.opcode_info.len(), 1);
+        assert_eq!(proto.opcode_info[0].opcode, Opcode::PsaSignHash as u32);
+        assert_eq!(proto.opcode_info[0].max_input_size, 64);
+
+        let resp: Result = proto.try_into().unwrap();
+        assert_eq!(resp.opcode_info, vec![info]);
+    }
+
+    #[test]
+    fn opcode_info_invalid_opcode() {
+        let proto = ResultProto {
+            opcodes: vec![1],
+            opcode_info: vec![OpcodeInfoProto {
+                opcode: 0xffff,
+                ..Default::default()
+            }],
+        };
+        let resp: std::result::Result<Result, ResponseStatus> = proto.try_into();
+        assert_eq!(resp.unwrap_err(), ResponseStatus::OpcodeDoesNotExist);
+    }
+
     #[test]
     fn list_opcodes_req_to_native() {
         let req_body = RequestBody::from_bytes(Vec::new());
@@ -153,6 +257,7 @@ mod test {
     fn result_list_opcodes_from_native() {
         let mut list_opcodes = Result {
             opcodes: HashSet::new(),
+            opcode_info: Vec::new(),
         };
         let _ = list_opcodes.opcodes.insert(Opcode::Ping);
 
@@ -166,6 +271,7 @@ mod test {
     fn list_opcodes_result_e2e() {
         let mut list_opcodes = Result {
             opcodes: HashSet::new(),
+            opcode_info: Vec::new(),
         };
         let _ = list_opcodes.opcodes.insert(Opcode::Ping);
 
@@ -1,9 +1,14 @@
 // Copyright 2019 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use super::generated_ops::ping::{Operation as OperationProto, Result as ResultProto};
-use crate::operations::ping::{Operation, Result};
-use crate::requests::ResponseStatus;
-use std::convert::TryFrom;
+use super::generated_ops::ping::{
+    Operation as OperationProto, Result as ResultProto,
+    WireProtocolVersion as WireProtocolVersionProto,
+};
+use crate::operations::ping::{Operation, Result, WireProtocolVersion};
+use crate::requests::{BodyType, ResponseStatus};
+use log::{error, warn};
+use num::FromPrimitive;
+use std::convert::{TryFrom, TryInto};
 
 impl TryFrom<OperationProto> for Operation {
     type Error = ResponseStatus;
@@ -21,13 +26,50 @@ impl TryFrom<Operation> for OperationProto {
     }
 }
 
+impl TryFrom<WireProtocolVersionProto> for WireProtocolVersion {
+    type Error = ResponseStatus;
+
+    fn try_from(version: WireProtocolVersionProto) -> std::result::Result<Self, Self::Error> {
+        Ok(WireProtocolVersion {
+            major: u8::try_from(version.major)?,
+            minor: u8::try_from(version.minor)?,
+        })
+    }
+}
+
+impl From<WireProtocolVersion> for WireProtocolVersionProto {
+    fn from(version: WireProtocolVersion) -> Self {
+        WireProtocolVersionProto {
+            major: u32::from(version.major),
+            minor: u32::from(version.minor),
+        }
+    }
+}
+
 impl TryFrom<Result> for ResultProto {
     type Error = ResponseStatus;
 
     fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        // A maximum body size of zero is the encoding of an absent one.
+        if result.max_body_size == Some(0) {
+            error!("The maximum body size of the Ping result cannot be zero.");
+            return Err(ResponseStatus::InvalidEncoding);
+        }
+
         let proto_response = ResultProto {
             wire_protocol_version_maj: u32::from(result.wire_protocol_version_maj),
             wire_protocol_version_min: u32::from(result.wire_protocol_version_min),
+            supported_wire_protocol_versions: result
+                .supported_wire_protocol_versions
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            supported_body_types: result
+                .supported_body_types
+                .into_iter()
+                .map(|body_type| body_type as u32)
+                .collect(),
+            max_body_size: result.max_body_size.unwrap_or(0).try_into()?,
         };
 
         Ok(proto_response)
@@ -38,9 +80,32 @@ impl TryFrom<ResultProto> for Result {
     type Error = ResponseStatus;
 
     fn try_from(response: ResultProto) -> std::result::Result<Self, Self::Error> {
+        let mut supported_wire_protocol_versions = Vec::new();
+        for version in response.supported_wire_protocol_versions {
+            supported_wire_protocol_versions.push(version.try_into()?);
+        }
+
+        // Body types unknown to this version of the interface cannot be used anyway.
+        let mut supported_body_types: Vec<BodyType> = Vec::new();
+        for body_type in response.supported_body_types {
+            match FromPrimitive::from_u32(body_type) {
+                Some(body_type) => supported_body_types.push(body_type),
+                None => warn!(
+                    "Ignoring unknown body type {} supported by the service.",
+                    body_type
+                ),
+            }
+        }
+
         Ok(Result {
             wire_protocol_version_maj: u8::try_from(response.wire_protocol_version_maj)?,
             wire_protocol_version_min: u8::try_from(response.wire_protocol_version_min)?,
+            supported_wire_protocol_versions,
+            supported_body_types,
+            max_body_size: match response.max_body_size {
+                0 => None,
+                max_body_size => Some(max_body_size.try_into()?),
+            },
         })
     }
 }
@@ -48,11 +113,15 @@ impl TryFrom<ResultProto> for Result {
 #[cfg(test)]
 mod test {
     // Operation <-> Proto conversions are not tested since they're too simple
-    use super::super::generated_ops::ping::Result as ResultProto;
+    use super::super::generated_ops::ping::{
+        Result as ResultProto, WireProtocolVersion as WireProtocolVersionProto,
+    };
     use super::super::{Convert, ProtobufConverter};
-    use crate::operations::ping::{Operation, Result};
+    use crate::operations::ping::{Operation, Result, WireProtocolVersion};
     use crate::operations::{NativeOperation, NativeResult};
-    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode};
+    use crate::requests::{
+        request::RequestBody, response::ResponseBody, BodyType, Opcode, ResponseStatus,
+    };
     use std::convert::TryInto;
 
     static CONVERTER: ProtobufConverter = ProtobufConverter {};
@@ -62,11 +131,55 @@ mod test {
         let proto = ResultProto {
             wire_protocol_version_maj: 1,
             wire_protocol_version_min: 1,
+            ..Default::default()
         };
         let resp: Result = proto.try_into().unwrap();
 
         assert!(resp.wire_protocol_version_maj == 1);
         assert!(resp.wire_protocol_version_min == 1);
+        assert!(resp.supported_wire_protocol_versions.is_empty());
+        assert!(resp.supported_body_types.is_empty());
+        assert!(resp.max_body_size.is_none());
+    }
+
+    #[test]
+    fn proto_to_resp_capabilities() {
+        let proto = ResultProto {
+            wire_protocol_version_maj: 1,
+            wire_protocol_version_min: 1,
+            supported_wire_protocol_versions: vec![
+                WireProtocolVersionProto { major: 1, minor: 0 },
+                WireProtocolVersionProto { major: 1, minor: 1 },
+            ],
+            supported_body_types: vec![0, 42],
+            max_body_size: 1 << 20,
+        };
+        let resp: Result = proto.try_into().unwrap();
+
+        assert_eq!(
+            resp.supported_wire_protocol_versions,
+            vec![
+                WireProtocolVersion { major: 1, minor: 0 },
+                WireProtocolVersion { major: 1, minor: 1 }
+            ]
+        );
+        assert_eq!(resp.supported_body_types, vec![BodyType::Protobuf]);
+        assert_eq!(resp.max_body_size, Some(1 << 20));
+    }
+
+    #[test]
+    fn proto_to_resp_invalid_version() {
+        let proto = ResultProto {
+            wire_protocol_version_maj: 1,
+            wire_protocol_version_min: 1,
+            supported_wire_protocol_versions: vec![WireProtocolVersionProto {
+                major: 256,
+                minor: 0,
+            }],
+            ..Default::default()
+        };
+        let resp: std::result::Result<Result, ResponseStatus> = proto.try_into();
+        assert_eq!(resp.unwrap_err(), ResponseStatus::InvalidEncoding);
     }
 
     #[test]
@@ -74,11 +187,34 @@ mod test {
         let resp: Result = Result {
             wire_protocol_version_maj: 1,
             wire_protocol_version_min: 1,
+            supported_wire_protocol_versions: vec![WireProtocolVersion { major: 1, minor: 0 }],
+            supported_body_types: vec![BodyType::Protobuf],
+            max_body_size: Some(1024),
         };
 
         let proto: ResultProto = resp.try_into().unwrap();
         assert!(proto.wire_protocol_version_maj == 1);
         assert!(proto.wire_protocol_version_min == 1);
+        assert_eq!(
+            proto.supported_wire_protocol_versions,
+            vec![WireProtocolVersionProto { major: 1, minor: 0 }]
+        );
+        assert_eq!(proto.supported_body_types, vec![0]);
+        assert_eq!(proto.max_body_size, 1024);
+    }
+
+    #[test]
+    fn resp_to_proto_zero_max_body_size() {
+        let resp: Result = Result {
+            wire_protocol_version_maj: 1,
+            wire_protocol_version_min: 1,
+            supported_wire_protocol_versions: Vec::new(),
+            supported_body_types: Vec::new(),
+            max_body_size: Some(0),
+        };
+
+        let proto: std::result::Result<ResultProto, ResponseStatus> = resp.try_into();
+        assert_eq!(proto.unwrap_err(), ResponseStatus::InvalidEncoding);
     }
 
     #[test]
@@ -125,6 +261,9 @@ mod test {
         let ping = Result {
             wire_protocol_version_maj: 1,
             wire_protocol_version_min: 0,
+            supported_wire_protocol_versions: vec![WireProtocolVersion { major: 1, minor: 0 }],
+            supported_body_types: vec![BodyType::Protobuf],
+            max_body_size: None,
         };
 
         let body = CONVERTER
@@ -138,6 +277,9 @@ mod test {
         let ping = Result {
             wire_protocol_version_maj: 1,
             wire_protocol_version_min: 0,
+            supported_wire_protocol_versions: vec![WireProtocolVersion { major: 1, minor: 0 }],
+            supported_body_types: vec![BodyType::Protobuf],
+            max_body_size: None,
         };
 
         let body = CONVERTER
@@ -153,6 +295,7 @@ mod test {
             NativeResult::Ping(result) => {
                 assert_eq!(result.wire_protocol_version_maj, 1);
                 assert_eq!(result.wire_protocol_version_min, 0);
+                assert_eq!(result.supported_body_types, vec![BodyType::Protobuf]);
             }
             _ => panic!("Expected ping"),
         }
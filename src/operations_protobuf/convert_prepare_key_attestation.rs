@@ -0,0 +1,248 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::prepare_key_attestation::{
+    prepare_key_attestation_output, prepare_key_attestation_params, Operation as OperationProto,
+    PrepareKeyAttestationOutput, PrepareKeyAttestationParams, Result as ResultProto,
+};
+use crate::operations::prepare_key_attestation::{Operation, Result};
+use crate::requests::ResponseStatus;
+use log::error;
+use std::convert::TryFrom;
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        match proto_op.parameters {
+            Some(PrepareKeyAttestationParams {
+                mechanism:
+                    Some(prepare_key_attestation_params::Mechanism::ActivateCredential(
+                        prepare_key_attestation_params::ActivateCredential {
+                            attested_key_name,
+                            attesting_key_name,
+                        },
+                    )),
+            }) => Ok(Operation::ActivateCredential {
+                attested_key_name,
+                attesting_key_name: if attesting_key_name.is_empty() {
+                    None
+                } else {
+                    Some(attesting_key_name)
+                },
+            }),
+            _ => {
+                error!("The parameters field of PrepareKeyAttestation::Operation message is not set (mandatory field).");
+                Err(ResponseStatus::InvalidEncoding)
+            }
+        }
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        match op {
+            Operation::ActivateCredential {
+                attested_key_name,
+                attesting_key_name,
+            } => Ok(OperationProto {
+                parameters: Some(PrepareKeyAttestationParams {
+                    mechanism: Some(
+                        prepare_key_attestation_params::Mechanism::ActivateCredential(
+                            prepare_key_attestation_params::ActivateCredential {
+                                attested_key_name,
+                                attesting_key_name: attesting_key_name.unwrap_or_default(),
+                            },
+                        ),
+                    ),
+                }),
+            }),
+        }
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        match proto_result.output {
+            Some(PrepareKeyAttestationOutput {
+                mechanism:
+                    Some(prepare_key_attestation_output::Mechanism::ActivateCredential(
+                        prepare_key_attestation_output::ActivateCredential {
+                            name,
+                            public,
+                            attesting_key_pub,
+                        },
+                    )),
+            }) => Ok(Result::ActivateCredential {
+                name: name.into(),
+                public: public.into(),
+                attesting_key_pub: attesting_key_pub.into(),
+            }),
+            _ => {
+                error!("The output field of PrepareKeyAttestation::Result message is not set (mandatory field).");
+                Err(ResponseStatus::InvalidEncoding)
+            }
+        }
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        match result {
+            Result::ActivateCredential {
+                name,
+                public,
+                attesting_key_pub,
+            } => Ok(ResultProto {
+                output: Some(PrepareKeyAttestationOutput {
+                    mechanism: Some(
+                        prepare_key_attestation_output::Mechanism::ActivateCredential(
+                            prepare_key_attestation_output::ActivateCredential {
+                                name: name.to_vec(),
+                                public: public.to_vec(),
+                                attesting_key_pub: attesting_key_pub.to_vec(),
+                            },
+                        ),
+                    ),
+                }),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::prepare_key_attestation::{
+        prepare_key_attestation_params, Operation as OperationProto, PrepareKeyAttestationParams,
+        Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::prepare_key_attestation::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode, ResponseStatus};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn prepare_key_attestation_proto_to_op() {
+        let proto = OperationProto {
+            parameters: Some(PrepareKeyAttestationParams {
+                mechanism: Some(
+                    prepare_key_attestation_params::Mechanism::ActivateCredential(
+                        prepare_key_attestation_params::ActivateCredential {
+                            attested_key_name: "attested key".to_string(),
+                            attesting_key_name: String::new(),
+                        },
+                    ),
+                ),
+            }),
+        };
+
+        let op: Operation = proto.try_into().expect("Failed conversion");
+        let Operation::ActivateCredential {
+            attested_key_name,
+            attesting_key_name,
+        } = op;
+        assert_eq!(attested_key_name, "attested key");
+        assert!(attesting_key_name.is_none());
+    }
+
+    #[test]
+    fn prepare_key_attestation_op_round_trip() {
+        let op = Operation::ActivateCredential {
+            attested_key_name: "attested key".to_string(),
+            attesting_key_name: Some("attesting key".to_string()),
+        };
+
+        let proto: OperationProto = op.try_into().expect("Failed conversion");
+        let op: Operation = proto.try_into().expect("Failed conversion");
+        let Operation::ActivateCredential {
+            attested_key_name,
+            attesting_key_name,
+        } = op;
+        assert_eq!(attested_key_name, "attested key");
+        assert_eq!(attesting_key_name, Some("attesting key".to_string()));
+    }
+
+    #[test]
+    fn prepare_key_attestation_missing_fields() {
+        let op: std::result::Result<Operation, ResponseStatus> =
+            OperationProto { parameters: None }.try_into();
+        assert_eq!(op.unwrap_err(), ResponseStatus::InvalidEncoding);
+
+        let result: std::result::Result<Result, ResponseStatus> =
+            ResultProto { output: None }.try_into();
+        assert_eq!(result.unwrap_err(), ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn prepare_key_attestation_result_round_trip() {
+        let result = Result::ActivateCredential {
+            name: vec![0x11, 0x22].into(),
+            public: vec![0x33, 0x44].into(),
+            attesting_key_pub: vec![0x55, 0x66].into(),
+        };
+
+        let proto: ResultProto = result.try_into().expect("Failed conversion");
+        let result: Result = proto.try_into().expect("Failed conversion");
+        let Result::ActivateCredential {
+            name,
+            public,
+            attesting_key_pub,
+        } = result;
+        assert_eq!(*name, vec![0x11, 0x22]);
+        assert_eq!(*public, vec![0x33, 0x44]);
+        assert_eq!(*attesting_key_pub, vec![0x55, 0x66]);
+    }
+
+    #[test]
+    fn prepare_key_attestation_e2e() {
+        let op = Operation::ActivateCredential {
+            attested_key_name: "attested key".to_string(),
+            attesting_key_name: None,
+        };
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PrepareKeyAttestation(op))
+            .expect("Failed to convert to body");
+        let _ = CONVERTER
+            .body_to_operation(body, Opcode::PrepareKeyAttestation)
+            .expect("Failed to convert to operation");
+
+        let result = Result::ActivateCredential {
+            name: vec![0x11, 0x22].into(),
+            public: vec![0x33, 0x44].into(),
+            attesting_key_pub: vec![0x55, 0x66].into(),
+        };
+        let body = CONVERTER
+            .result_to_body(NativeResult::PrepareKeyAttestation(result))
+            .expect("Failed to convert to body");
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PrepareKeyAttestation)
+            .is_ok());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PrepareKeyAttestation)
+            .is_err());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::PrepareKeyAttestation)
+            .is_err());
+    }
+}
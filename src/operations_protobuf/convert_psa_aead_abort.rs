@@ -0,0 +1,73 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_aead_abort::{Operation as OperationProto, Result as ResultProto};
+use crate::operations::psa_aead_abort::{Operation, Result};
+use crate::requests::ResponseStatus;
+use std::convert::TryFrom;
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            operation_handle: proto_op.operation_handle,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            operation_handle: op.operation_handle,
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(_proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {})
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(_result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {})
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_aead_abort::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::Opcode;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn aead_abort_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaAeadAbort(Operation {
+                operation_handle: 6,
+            }))
+            .expect("Failed to convert to body");
+        let op = CONVERTER
+            .body_to_operation(body, Opcode::PsaAeadAbort)
+            .expect("Failed to convert to operation");
+        match op {
+            NativeOperation::PsaAeadAbort(op) => assert_eq!(op.operation_handle, 6),
+            _ => panic!("Wrong operation type"),
+        }
+
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaAeadAbort(Result {}))
+            .expect("Failed to convert to body");
+        assert!(CONVERTER.body_to_result(body, Opcode::PsaAeadAbort).is_ok());
+    }
+}
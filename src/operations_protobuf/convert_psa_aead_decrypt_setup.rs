@@ -0,0 +1,134 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_aead_decrypt_setup::{
+    Operation as OperationProto, Result as ResultProto,
+};
+use crate::operations::psa_aead_decrypt_setup::{Operation, Result};
+use crate::requests::ResponseStatus;
+use log::error;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            key_name: proto_op.key_name,
+            alg: proto_op
+                .alg
+                .ok_or_else(|| {
+                    error!("The alg field of PsaAeadDecryptSetup::Operation message is not set (mandatory field).");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+            nonce: proto_op.nonce.into(),
+            lengths: proto_op.lengths.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            key_name: op.key_name,
+            alg: Some(op.alg.try_into()?),
+            nonce: op.nonce.to_vec(),
+            lengths: op.lengths.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            operation_handle: proto_result.operation_handle,
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            operation_handle: result.operation_handle,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_aead_decrypt_setup::Operation as OperationProto;
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_aead_decrypt_setup::{Operation, Result};
+    use crate::operations::psa_aead_encrypt_setup::Lengths;
+    use crate::operations::psa_algorithm::AeadWithDefaultLengthTag;
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, Opcode, ResponseStatus};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_op() -> Operation {
+        Operation {
+            key_name: "test name".to_string(),
+            alg: AeadWithDefaultLengthTag::Chacha20Poly1305.into(),
+            nonce: vec![0x11; 12].into(),
+            lengths: Some(Lengths {
+                ad_length: 0,
+                plaintext_length: 64,
+            }),
+        }
+    }
+
+    #[test]
+    fn aead_decrypt_setup_op_round_trip() {
+        let proto: OperationProto = get_op().try_into().expect("Failed to convert");
+        let op: Operation = proto.try_into().expect("Failed to convert");
+        assert_eq!(op.key_name, "test name");
+        assert_eq!(op.alg, get_op().alg);
+        assert_eq!(*op.nonce, vec![0x11; 12]);
+        assert_eq!(op.lengths, get_op().lengths);
+    }
+
+    #[test]
+    fn aead_decrypt_setup_missing_alg() {
+        let mut proto: OperationProto = get_op().try_into().expect("Failed to convert");
+        proto.alg = None;
+        let op: std::result::Result<Operation, ResponseStatus> = proto.try_into();
+        assert_eq!(op.unwrap_err(), ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn aead_decrypt_setup_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaAeadDecryptSetup(get_op()))
+            .expect("Failed to convert to body");
+        let _ = CONVERTER
+            .body_to_operation(body, Opcode::PsaAeadDecryptSetup)
+            .expect("Failed to convert to operation");
+
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaAeadDecryptSetup(Result {
+                operation_handle: 3,
+            }))
+            .expect("Failed to convert to body");
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PsaAeadDecryptSetup)
+            .is_ok());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaAeadDecryptSetup)
+            .is_err());
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_aead_encrypt_setup::{
+    Lengths as LengthsProto, Operation as OperationProto, Result as ResultProto,
+};
+use crate::operations::psa_aead_encrypt_setup::{Lengths, Operation, Result};
+use crate::requests::ResponseStatus;
+use log::error;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<LengthsProto> for Lengths {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_lengths: LengthsProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Lengths {
+            ad_length: proto_lengths.ad_length.try_into()?,
+            plaintext_length: proto_lengths.plaintext_length.try_into()?,
+        })
+    }
+}
+
+impl TryFrom<Lengths> for LengthsProto {
+    type Error = ResponseStatus;
+
+    fn try_from(lengths: Lengths) -> std::result::Result<Self, Self::Error> {
+        Ok(LengthsProto {
+            ad_length: lengths.ad_length.try_into()?,
+            plaintext_length: lengths.plaintext_length.try_into()?,
+        })
+    }
+}
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            key_name: proto_op.key_name,
+            alg: proto_op
+                .alg
+                .ok_or_else(|| {
+                    error!("The alg field of PsaAeadEncryptSetup::Operation message is not set (mandatory field).");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+            nonce: proto_op.nonce.into(),
+            lengths: proto_op.lengths.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            key_name: op.key_name,
+            alg: Some(op.alg.try_into()?),
+            nonce: op.nonce.to_vec(),
+            lengths: op.lengths.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            operation_handle: proto_result.operation_handle,
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            operation_handle: result.operation_handle,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_aead_encrypt_setup::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_aead_encrypt_setup::{Lengths, Operation, Result};
+    use crate::operations::psa_algorithm::{Aead, AeadWithDefaultLengthTag};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, Opcode, ResponseStatus};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_op() -> Operation {
+        Operation {
+            key_name: "test name".to_string(),
+            alg: Aead::AeadWithShortenedTag {
+                aead_alg: AeadWithDefaultLengthTag::Ccm,
+                tag_length: 8,
+            },
+            nonce: vec![0x11; 12].into(),
+            lengths: Some(Lengths {
+                ad_length: 16,
+                plaintext_length: 1 << 20,
+            }),
+        }
+    }
+
+    #[test]
+    fn aead_encrypt_setup_op_round_trip() {
+        let proto: OperationProto = get_op().try_into().expect("Failed to convert");
+        assert_eq!(proto.key_name, "test name");
+        assert_eq!(proto.nonce, vec![0x11; 12]);
+        assert!(proto.lengths.is_some());
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+        assert_eq!(op.alg, get_op().alg);
+        assert_eq!(*op.nonce, vec![0x11; 12]);
+        assert_eq!(op.lengths, get_op().lengths);
+    }
+
+    #[test]
+    fn aead_encrypt_setup_optional_lengths() {
+        let mut op = get_op();
+        op.lengths = None;
+        let proto: OperationProto = op.try_into().expect("Failed to convert");
+        assert!(proto.lengths.is_none());
+        let op: Operation = proto.try_into().expect("Failed to convert");
+        assert!(op.lengths.is_none());
+    }
+
+    #[test]
+    fn aead_encrypt_setup_missing_alg() {
+        let mut proto: OperationProto = get_op().try_into().expect("Failed to convert");
+        proto.alg = None;
+        let op: std::result::Result<Operation, ResponseStatus> = proto.try_into();
+        assert_eq!(op.unwrap_err(), ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn aead_encrypt_setup_result_round_trip() {
+        let proto: ResultProto = Result {
+            operation_handle: 12,
+        }
+        .try_into()
+        .expect("Failed to convert");
+        let result: Result = proto.try_into().expect("Failed to convert");
+        assert_eq!(result.operation_handle, 12);
+    }
+
+    #[test]
+    fn aead_encrypt_setup_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaAeadEncryptSetup(get_op()))
+            .expect("Failed to convert to body");
+        let _ = CONVERTER
+            .body_to_operation(body, Opcode::PsaAeadEncryptSetup)
+            .expect("Failed to convert to operation");
+
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaAeadEncryptSetup(Result {
+                operation_handle: 12,
+            }))
+            .expect("Failed to convert to body");
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PsaAeadEncryptSetup)
+            .is_ok());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaAeadEncryptSetup)
+            .is_err());
+    }
+}
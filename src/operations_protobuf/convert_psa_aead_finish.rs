@@ -0,0 +1,106 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_aead_finish::{Operation as OperationProto, Result as ResultProto};
+use crate::operations::psa_aead_finish::{Operation, Result};
+use crate::requests::ResponseStatus;
+use std::convert::TryFrom;
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            operation_handle: proto_op.operation_handle,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            operation_handle: op.operation_handle,
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            ciphertext: proto_result.ciphertext.into(),
+            tag: proto_result.tag.into(),
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            ciphertext: result.ciphertext.to_vec(),
+            tag: result.tag.to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_aead_finish::Result as ResultProto;
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_aead_finish::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{response::ResponseBody, Opcode};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn aead_finish_result_round_trip() {
+        let result = Result {
+            ciphertext: vec![0x11, 0x22].into(),
+            tag: vec![0x33; 16].into(),
+        };
+        let proto: ResultProto = result.try_into().expect("Failed to convert");
+        assert_eq!(proto.ciphertext, vec![0x11, 0x22]);
+        assert_eq!(proto.tag, vec![0x33; 16]);
+
+        let result: Result = proto.try_into().expect("Failed to convert");
+        assert_eq!(*result.ciphertext, vec![0x11, 0x22]);
+        assert_eq!(*result.tag, vec![0x33; 16]);
+    }
+
+    #[test]
+    fn aead_finish_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaAeadFinish(Operation {
+                operation_handle: 8,
+            }))
+            .expect("Failed to convert to body");
+        let _ = CONVERTER
+            .body_to_operation(body, Opcode::PsaAeadFinish)
+            .expect("Failed to convert to operation");
+
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaAeadFinish(Result {
+                ciphertext: vec![0x11, 0x22].into(),
+                tag: vec![0x33; 16].into(),
+            }))
+            .expect("Failed to convert to body");
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PsaAeadFinish)
+            .is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::PsaAeadFinish)
+            .is_err());
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_aead_update::{Operation as OperationProto, Result as ResultProto};
+use crate::operations::psa_aead_update::{Operation, Result};
+use crate::requests::ResponseStatus;
+use std::convert::TryFrom;
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            operation_handle: proto_op.operation_handle,
+            input: proto_op.input.into(),
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            operation_handle: op.operation_handle,
+            input: op.input.to_vec(),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            output: proto_result.output.into(),
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            output: result.output.to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_aead_update::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_aead_update::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{response::ResponseBody, Opcode};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn aead_update_round_trip() {
+        let op = Operation {
+            operation_handle: 4,
+            input: vec![0x11, 0x22, 0x33].into(),
+        };
+        let proto: OperationProto = op.try_into().expect("Failed to convert");
+        let op: Operation = proto.try_into().expect("Failed to convert");
+        assert_eq!(op.operation_handle, 4);
+        assert_eq!(*op.input, vec![0x11, 0x22, 0x33]);
+
+        let result = Result {
+            output: vec![0x44, 0x55].into(),
+        };
+        let proto: ResultProto = result.try_into().expect("Failed to convert");
+        let result: Result = proto.try_into().expect("Failed to convert");
+        assert_eq!(*result.output, vec![0x44, 0x55]);
+    }
+
+    #[test]
+    fn aead_update_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaAeadUpdate(Operation {
+                operation_handle: 4,
+                input: vec![0x11, 0x22, 0x33].into(),
+            }))
+            .expect("Failed to convert to body");
+        let _ = CONVERTER
+            .body_to_operation(body, Opcode::PsaAeadUpdate)
+            .expect("Failed to convert to operation");
+
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaAeadUpdate(Result {
+                output: vec![0x44, 0x55].into(),
+            }))
+            .expect("Failed to convert to body");
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PsaAeadUpdate)
+            .is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::PsaAeadUpdate)
+            .is_err());
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_aead_verify::{Operation as OperationProto, Result as ResultProto};
+use crate::operations::psa_aead_verify::{Operation, Result};
+use crate::requests::ResponseStatus;
+use std::convert::TryFrom;
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            operation_handle: proto_op.operation_handle,
+            tag: proto_op.tag.into(),
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            operation_handle: op.operation_handle,
+            tag: op.tag.to_vec(),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            plaintext: proto_result.plaintext.into(),
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            plaintext: result.plaintext.to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_aead_verify::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_aead_verify::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::Opcode;
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn aead_verify_round_trip() {
+        let op = Operation {
+            operation_handle: 2,
+            tag: vec![0x33; 16].into(),
+        };
+        let proto: OperationProto = op.try_into().expect("Failed to convert");
+        let op: Operation = proto.try_into().expect("Failed to convert");
+        assert_eq!(op.operation_handle, 2);
+        assert_eq!(*op.tag, vec![0x33; 16]);
+
+        let result = Result {
+            plaintext: vec![0x11, 0x22].into(),
+        };
+        let proto: ResultProto = result.try_into().expect("Failed to convert");
+        let result: Result = proto.try_into().expect("Failed to convert");
+        assert_eq!(*result.plaintext, vec![0x11, 0x22]);
+    }
+
+    #[test]
+    fn aead_verify_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaAeadVerify(Operation {
+                operation_handle: 2,
+                tag: vec![0x33; 16].into(),
+            }))
+            .expect("Failed to convert to body");
+        let _ = CONVERTER
+            .body_to_operation(body, Opcode::PsaAeadVerify)
+            .expect("Failed to convert to operation");
+
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaAeadVerify(Result {
+                plaintext: vec![0x11, 0x22].into(),
+            }))
+            .expect("Failed to convert to body");
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PsaAeadVerify)
+            .is_ok());
+    }
+}
@@ -0,0 +1,188 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::convert_psa_algorithm;
+use super::generated_ops::psa_cipher_decrypt::{
+    Operation as OperationProto, Result as ResultProto,
+};
+use crate::operations::psa_cipher_decrypt::{Operation, Result};
+use crate::requests::ResponseStatus;
+use std::convert::TryFrom;
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            key_name: proto_op.key_name,
+            alg: convert_psa_algorithm::i32_to_cipher(proto_op.alg)?,
+            ciphertext: proto_op.ciphertext.into(),
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            key_name: op.key_name,
+            alg: convert_psa_algorithm::cipher_to_i32(op.alg),
+            ciphertext: op.ciphertext.to_vec(),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            plaintext: proto_result.plaintext.into(),
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            plaintext: result.plaintext.to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_algorithm as algorithm_proto;
+    use super::super::generated_ops::psa_cipher_decrypt::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_algorithm::Cipher;
+    use crate::operations::psa_cipher_decrypt::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode, ResponseStatus};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn cipher_proto_to_op() {
+        let mut proto: OperationProto = Default::default();
+        let ciphertext = vec![0x11, 0x22, 0x33];
+        let key_name = "test name".to_string();
+        proto.ciphertext = ciphertext.clone();
+        proto.alg = algorithm_proto::algorithm::Cipher::CbcPkcs7.into();
+        proto.key_name = key_name.clone();
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(*op.ciphertext, ciphertext);
+        assert_eq!(op.key_name, key_name);
+        assert_eq!(op.alg, Cipher::CbcPkcs7);
+    }
+
+    #[test]
+    fn cipher_op_to_proto() {
+        let ciphertext = vec![0x11, 0x22, 0x33];
+        let key_name = "test name".to_string();
+
+        let op = Operation {
+            ciphertext: ciphertext.clone().into(),
+            alg: Cipher::Ctr,
+            key_name: key_name.clone(),
+        };
+
+        let proto: OperationProto = op.try_into().expect("Failed to convert");
+
+        assert_eq!(proto.ciphertext, ciphertext);
+        assert_eq!(proto.key_name, key_name);
+        assert_eq!(proto.alg, algorithm_proto::algorithm::Cipher::Ctr as i32);
+    }
+
+    #[test]
+    fn cipher_proto_none_alg() {
+        let proto = OperationProto {
+            key_name: "test name".to_string(),
+            alg: algorithm_proto::algorithm::Cipher::None.into(),
+            ciphertext: vec![0x11, 0x22, 0x33],
+        };
+
+        let op: std::result::Result<Operation, ResponseStatus> = proto.try_into();
+        assert_eq!(op.unwrap_err(), ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn cipher_proto_to_resp() {
+        let mut proto: ResultProto = Default::default();
+        let plaintext = vec![0x11, 0x22, 0x33];
+        proto.plaintext = plaintext.clone();
+
+        let result: Result = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(*result.plaintext, plaintext);
+    }
+
+    #[test]
+    fn cipher_resp_to_proto() {
+        let plaintext = vec![0x11, 0x22, 0x33];
+        let result = Result {
+            plaintext: plaintext.clone().into(),
+        };
+
+        let proto: ResultProto = result.try_into().expect("Failed to convert");
+
+        assert_eq!(proto.plaintext, plaintext);
+    }
+
+    #[test]
+    fn psa_cipher_decrypt_op_e2e() {
+        let op = Operation {
+            ciphertext: vec![0x11, 0x22, 0x33].into(),
+            alg: Cipher::Xts,
+            key_name: "test name".to_string(),
+        };
+
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaCipherDecrypt(op))
+            .expect("Failed to convert to body");
+
+        let _ = CONVERTER
+            .body_to_operation(body, Opcode::PsaCipherDecrypt)
+            .expect("Failed to convert to operation");
+    }
+
+    #[test]
+    fn resp_cipher_decrypt_e2e() {
+        let result = Result {
+            plaintext: vec![0x11, 0x22, 0x33].into(),
+        };
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaCipherDecrypt(result))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PsaCipherDecrypt)
+            .is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::PsaCipherDecrypt)
+            .is_err());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaCipherDecrypt)
+            .is_err());
+    }
+}
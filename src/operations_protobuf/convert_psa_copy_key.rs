@@ -0,0 +1,130 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_copy_key::{Operation as OperationProto, Result as ResultProto};
+use crate::operations::psa_copy_key::{Operation, Result};
+use crate::requests::ResponseStatus;
+use log::error;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            key_name: proto_op.key_name,
+            target_key_name: proto_op.target_key_name,
+            attributes: proto_op
+                .attributes
+                .ok_or_else(|| {
+                    error!("The attributes field of PsaCopyKey::Operation message is not set (mandatory field).");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            key_name: op.key_name,
+            target_key_name: op.target_key_name,
+            attributes: Some(op.attributes.try_into()?),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(_proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {})
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(_result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {})
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_copy_key::Operation as OperationProto;
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_algorithm::{Algorithm, Cipher};
+    use crate::operations::psa_copy_key::{Operation, Result};
+    use crate::operations::psa_key_attributes::{Attributes, Lifetime, Policy, Type, UsageFlags};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, Opcode, ResponseStatus};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_op() -> Operation {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_decrypt();
+        Operation {
+            key_name: "source key".to_string(),
+            target_key_name: "target key".to_string(),
+            attributes: Attributes {
+                lifetime: Lifetime::Persistent,
+                key_type: Type::Aes,
+                bits: 128,
+                policy: Policy {
+                    usage_flags,
+                    permitted_algorithms: Algorithm::Cipher(Cipher::Ctr),
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn copy_key_op_round_trip() {
+        let proto: OperationProto = get_op().try_into().expect("Failed conversion");
+        assert_eq!(proto.key_name, "source key");
+        assert_eq!(proto.target_key_name, "target key");
+
+        let op: Operation = proto.try_into().expect("Failed conversion");
+        assert_eq!(op.key_name, "source key");
+        assert_eq!(op.target_key_name, "target key");
+        assert_eq!(op.attributes, get_op().attributes);
+    }
+
+    #[test]
+    fn copy_key_missing_attributes() {
+        let mut proto: OperationProto = get_op().try_into().expect("Failed conversion");
+        proto.attributes = None;
+        let op: std::result::Result<Operation, ResponseStatus> = proto.try_into();
+        assert_eq!(op.unwrap_err(), ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn copy_key_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaCopyKey(get_op()))
+            .expect("Failed to convert to body");
+        let _ = CONVERTER
+            .body_to_operation(body, Opcode::PsaCopyKey)
+            .expect("Failed to convert to operation");
+
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaCopyKey(Result {}))
+            .expect("Failed to convert to body");
+        assert!(CONVERTER.body_to_result(body, Opcode::PsaCopyKey).is_ok());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaCopyKey)
+            .is_err());
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_get_key_attributes::{
+    Operation as OperationProto, Result as ResultProto,
+};
+use crate::operations::psa_get_key_attributes::{Operation, Result};
+use crate::requests::ResponseStatus;
+use log::error;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            key_name: proto_op.key_name,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            key_name: op.key_name,
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            attributes: proto_result
+                .attributes
+                .ok_or_else(|| {
+                    error!("The attributes field of PsaGetKeyAttributes::Result message is not set (mandatory field).");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            attributes: Some(result.attributes.try_into()?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_get_key_attributes::Result as ResultProto;
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, Hash};
+    use crate::operations::psa_get_key_attributes::{Operation, Result};
+    use crate::operations::psa_key_attributes::{Attributes, Lifetime, Policy, Type, UsageFlags};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{response::ResponseBody, Opcode, ResponseStatus};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_attrs() -> Attributes {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_sign_hash().set_verify_hash();
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::RsaKeyPair,
+            bits: 2048,
+            policy: Policy {
+                usage_flags,
+                permitted_algorithms: Algorithm::AsymmetricSignature(
+                    AsymmetricSignature::RsaPkcs1v15Sign {
+                        hash_alg: Hash::Sha256.into(),
+                    },
+                ),
+            },
+        }
+    }
+
+    #[test]
+    fn get_key_attributes_result_round_trip() {
+        let proto: ResultProto = Result {
+            attributes: get_attrs(),
+        }
+        .try_into()
+        .expect("Failed conversion");
+        let result: Result = proto.try_into().expect("Failed conversion");
+        assert_eq!(result.attributes, get_attrs());
+    }
+
+    #[test]
+    fn get_key_attributes_missing_attributes() {
+        let result: std::result::Result<Result, ResponseStatus> =
+            ResultProto { attributes: None }.try_into();
+        assert_eq!(result.unwrap_err(), ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn get_key_attributes_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaGetKeyAttributes(Operation {
+                key_name: "test name".to_string(),
+            }))
+            .expect("Failed to convert to body");
+        let op = CONVERTER
+            .body_to_operation(body, Opcode::PsaGetKeyAttributes)
+            .expect("Failed to convert to operation");
+        match op {
+            NativeOperation::PsaGetKeyAttributes(op) => assert_eq!(op.key_name, "test name"),
+            _ => panic!("Wrong operation type"),
+        }
+
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaGetKeyAttributes(Result {
+                attributes: get_attrs(),
+            }))
+            .expect("Failed to convert to body");
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PsaGetKeyAttributes)
+            .is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::PsaGetKeyAttributes)
+            .is_err());
+    }
+}
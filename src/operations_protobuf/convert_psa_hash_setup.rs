@@ -0,0 +1,119 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::convert_psa_algorithm;
+use super::generated_ops::psa_hash_setup::{Operation as OperationProto, Result as ResultProto};
+use crate::operations::psa_hash_setup::{Operation, Result};
+use crate::requests::ResponseStatus;
+use std::convert::TryFrom;
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            alg: convert_psa_algorithm::i32_to_hash(proto_op.alg)?,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            alg: convert_psa_algorithm::hash_to_i32(op.alg),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            operation_handle: proto_result.operation_handle,
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            operation_handle: result.operation_handle,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_algorithm as algorithm_proto;
+    use super::super::generated_ops::psa_hash_setup::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_algorithm::Hash;
+    use crate::operations::psa_hash_setup::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, Opcode, ResponseStatus};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn hash_setup_proto_to_op() {
+        let proto = OperationProto {
+            alg: algorithm_proto::algorithm::Hash::Sha256.into(),
+        };
+        let op: Operation = proto.try_into().expect("Failed to convert");
+        assert_eq!(op.alg, Hash::Sha256);
+
+        let proto = OperationProto {
+            alg: algorithm_proto::algorithm::Hash::None.into(),
+        };
+        let op: std::result::Result<Operation, ResponseStatus> = proto.try_into();
+        assert_eq!(op.unwrap_err(), ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn hash_setup_result_round_trip() {
+        let proto: ResultProto = Result {
+            operation_handle: 42,
+        }
+        .try_into()
+        .expect("Failed to convert");
+        assert_eq!(proto.operation_handle, 42);
+        let result: Result = proto.try_into().expect("Failed to convert");
+        assert_eq!(result.operation_handle, 42);
+    }
+
+    #[test]
+    fn hash_setup_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaHashSetup(Operation {
+                alg: Hash::Sha512,
+            }))
+            .expect("Failed to convert to body");
+        let _ = CONVERTER
+            .body_to_operation(body, Opcode::PsaHashSetup)
+            .expect("Failed to convert to operation");
+
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaHashSetup(Result {
+                operation_handle: 7,
+            }))
+            .expect("Failed to convert to body");
+        assert!(CONVERTER.body_to_result(body, Opcode::PsaHashSetup).is_ok());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaHashSetup)
+            .is_err());
+    }
+}
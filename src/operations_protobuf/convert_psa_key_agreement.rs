@@ -0,0 +1,188 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_key_agreement::{Operation as OperationProto, Result as ResultProto};
+use crate::operations::psa_key_agreement::{Operation, Result};
+use crate::requests::ResponseStatus;
+use log::error;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            alg: proto_op
+                .alg
+                .ok_or_else(|| {
+                    error!("The alg field of PsaKeyAgreement::Operation message is not set (mandatory field).");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+            private_key_name: proto_op.private_key_name,
+            peer_key: proto_op.peer_key.into(),
+            key_name: proto_op.key_name,
+            attributes: proto_op
+                .attributes
+                .ok_or_else(|| {
+                    error!("The attributes field of PsaKeyAgreement::Operation message is not set (mandatory field).");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            alg: Some(op.alg.try_into()?),
+            private_key_name: op.private_key_name,
+            peer_key: op.peer_key.to_vec(),
+            key_name: op.key_name,
+            attributes: Some(op.attributes.try_into()?),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(_proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {})
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(_result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {})
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_key_agreement::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_algorithm::{
+        Algorithm, Hash, KeyAgreement, KeyDerivation, RawKeyAgreement,
+    };
+    use crate::operations::psa_key_agreement::{Operation, Result};
+    use crate::operations::psa_key_attributes::{Attributes, Lifetime, Policy, Type, UsageFlags};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode, ResponseStatus};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    const ALG: KeyAgreement = KeyAgreement::WithKeyDerivation {
+        ka_alg: RawKeyAgreement::Ecdh,
+        kdf_alg: KeyDerivation::Hkdf {
+            hash_alg: Hash::Sha256,
+        },
+    };
+
+    fn get_op() -> Operation {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_encrypt().set_decrypt();
+        Operation {
+            alg: ALG,
+            private_key_name: "private key".to_string(),
+            peer_key: vec![0x11, 0x22, 0x33].into(),
+            key_name: "session key".to_string(),
+            attributes: Attributes {
+                lifetime: Lifetime::Persistent,
+                key_type: Type::Aes,
+                bits: 128,
+                policy: Policy {
+                    usage_flags,
+                    permitted_algorithms: Algorithm::None,
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn psa_key_agreement_op_round_trip() {
+        let op = get_op();
+        let attributes = op.attributes;
+
+        let proto: OperationProto = op.try_into().expect("Failed conversion");
+        assert_eq!(proto.private_key_name, "private key");
+        assert_eq!(proto.peer_key, vec![0x11, 0x22, 0x33]);
+        assert_eq!(proto.key_name, "session key");
+
+        let op: Operation = proto.try_into().expect("Failed conversion");
+        assert_eq!(op.alg, ALG);
+        assert_eq!(op.private_key_name, "private key");
+        assert_eq!(*op.peer_key, vec![0x11, 0x22, 0x33]);
+        assert_eq!(op.key_name, "session key");
+        assert_eq!(op.attributes, attributes);
+    }
+
+    #[test]
+    fn psa_key_agreement_proto_missing_fields() {
+        let proto: OperationProto = get_op().try_into().expect("Failed conversion");
+
+        let mut missing_alg = proto.clone();
+        missing_alg.alg = None;
+        let op: std::result::Result<Operation, ResponseStatus> = missing_alg.try_into();
+        assert_eq!(op.unwrap_err(), ResponseStatus::InvalidEncoding);
+
+        let mut missing_attributes = proto;
+        missing_attributes.attributes = None;
+        let op: std::result::Result<Operation, ResponseStatus> = missing_attributes.try_into();
+        assert_eq!(op.unwrap_err(), ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn psa_key_agreement_res_conversions() {
+        let _res: Result = ResultProto {}.try_into().expect("Failed conversion");
+        let _proto: ResultProto = Result {}.try_into().expect("Failed conversion");
+    }
+
+    #[test]
+    fn psa_key_agreement_op_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaKeyAgreement(get_op()))
+            .expect("Failed to convert to body");
+
+        let _ = CONVERTER
+            .body_to_operation(body, Opcode::PsaKeyAgreement)
+            .expect("Failed to convert to operation");
+    }
+
+    #[test]
+    fn psa_key_agreement_res_e2e() {
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaKeyAgreement(Result {}))
+            .expect("Failed to convert to body");
+
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PsaKeyAgreement)
+            .is_ok());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaKeyAgreement)
+            .is_err());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::PsaKeyAgreement)
+            .is_err());
+    }
+}
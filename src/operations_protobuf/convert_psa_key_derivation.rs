@@ -0,0 +1,329 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_key_derivation::{
+    operation as operation_proto, Operation as OperationProto, OutputBytes as OutputBytesProto,
+    OutputKey as OutputKeyProto, Result as ResultProto,
+};
+use crate::operations::psa_key_derivation::{Operation, Output, Result};
+use crate::requests::ResponseStatus;
+use crate::secrecy::{ExposeSecret, Secret};
+use log::error;
+use std::convert::{TryFrom, TryInto};
+use zeroize::Zeroizing;
+
+// Optional inputs are encoded as empty byte strings when absent.
+fn bytes_to_input(bytes: Vec<u8>) -> Option<Zeroizing<Vec<u8>>> {
+    match bytes.len() {
+        0 => None,
+        _ => Some(Zeroizing::new(bytes)),
+    }
+}
+
+fn input_to_bytes(input: Option<Zeroizing<Vec<u8>>>) -> Vec<u8> {
+    match input {
+        Some(input) => input.to_vec(),
+        None => vec![],
+    }
+}
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        let output = match proto_op.output {
+            Some(operation_proto::Output::Bytes(bytes)) => Output::Bytes {
+                length: bytes.length as usize,
+            },
+            Some(operation_proto::Output::Key(key)) => Output::Key {
+                key_name: key.key_name,
+                attributes: key
+                    .attributes
+                    .ok_or_else(|| {
+                        error!("The attributes field of PsaKeyDerivation::OutputKey message is not set (mandatory field).");
+                        ResponseStatus::InvalidEncoding
+                    })?
+                    .try_into()?,
+            },
+            None => {
+                error!("The output field of PsaKeyDerivation::Operation message is not set (mandatory field).");
+                return Err(ResponseStatus::InvalidEncoding);
+            }
+        };
+
+        Ok(Operation {
+            key_name: proto_op.key_name,
+            alg: proto_op
+                .alg
+                .ok_or_else(|| {
+                    error!("The alg field of PsaKeyDerivation::Operation message is not set (mandatory field).");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+            salt: bytes_to_input(proto_op.salt),
+            info: bytes_to_input(proto_op.info),
+            seed: bytes_to_input(proto_op.seed),
+            label: bytes_to_input(proto_op.label),
+            output,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        let alg = Some(op.alg.try_into()?);
+        let output = match op.output {
+            Output::Bytes { length } => operation_proto::Output::Bytes(OutputBytesProto {
+                length: length.try_into()?,
+            }),
+            Output::Key {
+                key_name,
+                attributes,
+            } => operation_proto::Output::Key(OutputKeyProto {
+                key_name,
+                attributes: Some(attributes.try_into()?),
+            }),
+        };
+        Ok(OperationProto {
+            key_name: op.key_name,
+            alg,
+            salt: input_to_bytes(op.salt),
+            info: input_to_bytes(op.info),
+            seed: input_to_bytes(op.seed),
+            label: input_to_bytes(op.label),
+            output: Some(output),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        let output = match proto_result.output.len() {
+            0 => None,
+            _ => Some(Secret::new(proto_result.output)),
+        };
+        Ok(Result { output })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        let output = match result.output {
+            Some(output) => output.expose_secret().to_vec(),
+            None => vec![],
+        };
+        Ok(ResultProto { output })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_algorithm as algorithm_proto;
+    use super::super::generated_ops::psa_key_derivation::{
+        operation as operation_proto, Operation as OperationProto, OutputBytes as OutputBytesProto,
+        Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_algorithm::{Algorithm, Hash, KeyDerivation};
+    use crate::operations::psa_key_attributes::{Attributes, Lifetime, Policy, Type, UsageFlags};
+    use crate::operations::psa_key_derivation::{Operation, Output, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode, ResponseStatus};
+    use crate::secrecy::{ExposeSecret, Secret};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_hkdf_proto() -> algorithm_proto::algorithm::KeyDerivation {
+        algorithm_proto::algorithm::KeyDerivation {
+            variant: Some(algorithm_proto::algorithm::key_derivation::Variant::Hkdf(
+                algorithm_proto::algorithm::key_derivation::Hkdf {
+                    hash_alg: algorithm_proto::algorithm::Hash::Sha256.into(),
+                },
+            )),
+        }
+    }
+
+    fn get_op() -> Operation {
+        let mut usage_flags = UsageFlags::default();
+        let _ = usage_flags.set_encrypt();
+        Operation {
+            key_name: "test name".to_string(),
+            alg: KeyDerivation::Tls12Prf {
+                hash_alg: Hash::Sha384,
+            },
+            salt: None,
+            info: None,
+            seed: Some(vec![0x11, 0x22, 0x33].into()),
+            label: Some(vec![0x44, 0x55].into()),
+            output: Output::Key {
+                key_name: "derived key".to_string(),
+                attributes: Attributes {
+                    lifetime: Lifetime::Persistent,
+                    key_type: Type::Aes,
+                    bits: 256,
+                    policy: Policy {
+                        usage_flags,
+                        permitted_algorithms: Algorithm::None,
+                    },
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn key_derivation_proto_to_op() {
+        let proto = OperationProto {
+            key_name: "test name".to_string(),
+            alg: Some(get_hkdf_proto()),
+            salt: vec![0x11, 0x22, 0x33],
+            info: vec![0x44],
+            seed: vec![],
+            label: vec![],
+            output: Some(operation_proto::Output::Bytes(OutputBytesProto {
+                length: 42,
+            })),
+        };
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(op.key_name, "test name");
+        assert_eq!(
+            op.alg,
+            KeyDerivation::Hkdf {
+                hash_alg: Hash::Sha256
+            }
+        );
+        assert_eq!(*op.salt.unwrap(), vec![0x11, 0x22, 0x33]);
+        assert_eq!(*op.info.unwrap(), vec![0x44]);
+        assert!(op.seed.is_none());
+        assert!(op.label.is_none());
+        assert_eq!(op.output, Output::Bytes { length: 42 });
+    }
+
+    #[test]
+    fn key_derivation_proto_missing_fields() {
+        let mut proto = OperationProto {
+            key_name: "test name".to_string(),
+            alg: None,
+            output: Some(operation_proto::Output::Bytes(OutputBytesProto {
+                length: 42,
+            })),
+            ..Default::default()
+        };
+        let op: std::result::Result<Operation, ResponseStatus> = proto.clone().try_into();
+        assert_eq!(op.unwrap_err(), ResponseStatus::InvalidEncoding);
+
+        proto.alg = Some(get_hkdf_proto());
+        proto.output = None;
+        let op: std::result::Result<Operation, ResponseStatus> = proto.try_into();
+        assert_eq!(op.unwrap_err(), ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn key_derivation_op_to_proto() {
+        let proto: OperationProto = get_op().try_into().expect("Failed to convert");
+
+        assert_eq!(proto.key_name, "test name");
+        assert!(proto.alg.is_some());
+        assert!(proto.salt.is_empty());
+        assert!(proto.info.is_empty());
+        assert_eq!(proto.seed, vec![0x11, 0x22, 0x33]);
+        assert_eq!(proto.label, vec![0x44, 0x55]);
+        match proto.output {
+            Some(operation_proto::Output::Key(key)) => {
+                assert_eq!(key.key_name, "derived key");
+                assert!(key.attributes.is_some());
+            }
+            _ => panic!("Expected a key output"),
+        }
+    }
+
+    #[test]
+    fn key_derivation_op_round_trip() {
+        let op = get_op();
+        let alg = op.alg;
+        let output = op.output.clone();
+
+        let proto: OperationProto = op.try_into().expect("Failed to convert");
+        let op: Operation = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(op.alg, alg);
+        assert_eq!(op.output, output);
+    }
+
+    #[test]
+    fn key_derivation_proto_to_resp() {
+        let proto = ResultProto {
+            output: vec![0x11, 0x22, 0x33],
+        };
+        let result: Result = proto.try_into().expect("Failed to convert");
+        assert_eq!(
+            result.output.unwrap().expose_secret(),
+            &vec![0x11, 0x22, 0x33]
+        );
+
+        let result: Result = ResultProto::default()
+            .try_into()
+            .expect("Failed to convert");
+        assert!(result.output.is_none());
+    }
+
+    #[test]
+    fn key_derivation_resp_to_proto() {
+        let result = Result {
+            output: Some(Secret::new(vec![0x11, 0x22, 0x33])),
+        };
+        let proto: ResultProto = result.try_into().expect("Failed to convert");
+        assert_eq!(proto.output, vec![0x11, 0x22, 0x33]);
+    }
+
+    #[test]
+    fn psa_key_derivation_op_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaKeyDerivation(get_op()))
+            .expect("Failed to convert to body");
+
+        let _ = CONVERTER
+            .body_to_operation(body, Opcode::PsaKeyDerivation)
+            .expect("Failed to convert to operation");
+    }
+
+    #[test]
+    fn resp_key_derivation_e2e() {
+        let result = Result {
+            output: Some(Secret::new(vec![0x11, 0x22, 0x33])),
+        };
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaKeyDerivation(result))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PsaKeyDerivation)
+            .is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::PsaKeyDerivation)
+            .is_err());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaKeyDerivation)
+            .is_err());
+    }
+}
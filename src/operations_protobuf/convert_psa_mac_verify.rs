@@ -0,0 +1,142 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_mac_verify::{Operation as OperationProto, Result as ResultProto};
+use crate::operations::psa_mac_verify::{Operation, Result};
+use crate::requests::ResponseStatus;
+use log::error;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            key_name: proto_op.key_name,
+            alg: proto_op
+                .alg
+                .ok_or_else(|| {
+                    error!("The alg field of PsaMacVerify::Operation message is not set (mandatory field).");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+            input: proto_op.input.into(),
+            mac: proto_op.mac.into(),
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        let alg = Some(op.alg.try_into()?);
+        Ok(OperationProto {
+            key_name: op.key_name,
+            alg,
+            input: op.input.to_vec(),
+            mac: op.mac.to_vec(),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(_proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {})
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(_result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {})
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_mac_verify::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_algorithm::{FullLengthMac, Hash, Mac};
+    use crate::operations::psa_mac_verify::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, Opcode};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn mac_op_round_trip() {
+        let input = vec![0x11, 0x22, 0x33];
+        let mac = vec![0x44, 0x55, 0x66];
+        let key_name = "test name".to_string();
+        let alg = Mac::FullLength(FullLengthMac::Hmac {
+            hash_alg: Hash::Sha256,
+        });
+
+        let op = Operation {
+            input: input.clone().into(),
+            mac: mac.clone().into(),
+            alg,
+            key_name: key_name.clone(),
+        };
+
+        let proto: OperationProto = op.try_into().expect("Failed to convert");
+        assert_eq!(proto.input, input);
+        assert_eq!(proto.mac, mac);
+        assert_eq!(proto.key_name, key_name);
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+        assert_eq!(*op.input, input);
+        assert_eq!(*op.mac, mac);
+        assert_eq!(op.key_name, key_name);
+        assert_eq!(op.alg, alg);
+    }
+
+    #[test]
+    fn mac_proto_to_resp() {
+        let proto: ResultProto = Default::default();
+        let _result: Result = proto.try_into().expect("Failed to convert");
+    }
+
+    #[test]
+    fn psa_mac_verify_op_e2e() {
+        let op = Operation {
+            input: vec![0x11, 0x22, 0x33].into(),
+            mac: vec![0x44, 0x55, 0x66].into(),
+            alg: Mac::FullLength(FullLengthMac::Cmac),
+            key_name: "test name".to_string(),
+        };
+
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaMacVerify(op))
+            .expect("Failed to convert to body");
+
+        let _ = CONVERTER
+            .body_to_operation(body, Opcode::PsaMacVerify)
+            .expect("Failed to convert to operation");
+    }
+
+    #[test]
+    fn resp_mac_verify_e2e() {
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaMacVerify(Result {}))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER.body_to_result(body, Opcode::PsaMacVerify).is_ok());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaMacVerify)
+            .is_err());
+    }
+}
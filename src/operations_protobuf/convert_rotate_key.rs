@@ -0,0 +1,124 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::rotate_key::{Operation as OperationProto, Result as ResultProto};
+use crate::operations::rotate_key::{Operation, Result};
+use crate::requests::ResponseStatus;
+use std::convert::TryFrom;
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            key_name: proto_op.key_name,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            key_name: op.key_name,
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            version: proto_result.version,
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            version: result.version,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::rotate_key::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::rotate_key::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn rotate_key_op_round_trip() {
+        let proto: OperationProto = Operation {
+            key_name: String::from("some key"),
+        }
+        .try_into()
+        .expect("Failed conversion");
+        assert_eq!(proto.key_name, "some key");
+
+        let op: Operation = proto.try_into().expect("Failed conversion");
+        assert_eq!(op.key_name, "some key");
+    }
+
+    #[test]
+    fn rotate_key_res_round_trip() {
+        let proto: ResultProto = Result { version: 3 }.try_into().expect("Failed conversion");
+        assert_eq!(proto.version, 3);
+
+        let result: Result = proto.try_into().expect("Failed conversion");
+        assert_eq!(result.version, 3);
+    }
+
+    #[test]
+    fn rotate_key_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::RotateKey(Operation {
+                key_name: String::from("some key"),
+            }))
+            .expect("Failed to convert to body");
+        let _ = CONVERTER
+            .body_to_operation(body, Opcode::RotateKey)
+            .expect("Failed to convert to operation");
+
+        let body = CONVERTER
+            .result_to_body(NativeResult::RotateKey(Result { version: 2 }))
+            .expect("Failed to convert to body");
+        match CONVERTER
+            .body_to_result(body, Opcode::RotateKey)
+            .expect("Failed to convert to result")
+        {
+            NativeResult::RotateKey(result) => assert_eq!(result.version, 2),
+            _ => panic!("Expected a RotateKey result"),
+        }
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::RotateKey)
+            .is_err());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::RotateKey)
+            .is_err());
+    }
+}
@@ -0,0 +1,12 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(string, tag="1")]
+    pub key_name: std::string::String,
+    #[prost(bytes, repeated, tag="2")]
+    pub chain: ::std::vec::Vec<std::vec::Vec<u8>>,
+    #[prost(bool, tag="3")]
+    pub replace: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+}
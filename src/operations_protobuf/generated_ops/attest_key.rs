@@ -0,0 +1,66 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttestationMechanismParams {
+    #[prost(oneof="attestation_mechanism_params::Mechanism", tags="1, 2")]
+    pub mechanism: ::std::option::Option<attestation_mechanism_params::Mechanism>,
+}
+pub mod attestation_mechanism_params {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ActivateCredential {
+        #[prost(bytes, tag="1")]
+        pub credential: std::vec::Vec<u8>,
+        #[prost(bytes, tag="2")]
+        pub secret: std::vec::Vec<u8>,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Certify {
+        #[prost(bytes, tag="1")]
+        pub nonce: std::vec::Vec<u8>,
+    }
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Mechanism {
+        #[prost(message, tag="1")]
+        ActivateCredential(ActivateCredential),
+        #[prost(message, tag="2")]
+        Certify(Certify),
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(string, tag="1")]
+    pub attested_key_name: std::string::String,
+    #[prost(message, optional, tag="2")]
+    pub parameters: ::std::option::Option<AttestationMechanismParams>,
+    #[prost(string, tag="3")]
+    pub attesting_key_name: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttestationOutput {
+    #[prost(oneof="attestation_output::Mechanism", tags="1, 2")]
+    pub mechanism: ::std::option::Option<attestation_output::Mechanism>,
+}
+pub mod attestation_output {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ActivateCredential {
+        #[prost(bytes, tag="1")]
+        pub credential: std::vec::Vec<u8>,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Certify {
+        #[prost(bytes, tag="1")]
+        pub attestation: std::vec::Vec<u8>,
+        #[prost(bytes, tag="2")]
+        pub signature: std::vec::Vec<u8>,
+    }
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Mechanism {
+        #[prost(message, tag="1")]
+        ActivateCredential(ActivateCredential),
+        #[prost(message, tag="2")]
+        Certify(Certify),
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+    #[prost(message, optional, tag="1")]
+    pub output: ::std::option::Option<AttestationOutput>,
+}
@@ -0,0 +1,27 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchItem {
+    #[prost(uint32, tag="1")]
+    pub opcode: u32,
+    #[prost(bytes, tag="2")]
+    pub body: std::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(message, repeated, tag="1")]
+    pub operations: ::std::vec::Vec<BatchItem>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchItemResult {
+    #[prost(uint32, tag="1")]
+    pub opcode: u32,
+    /// Cast down to 16 bits
+    #[prost(uint32, tag="2")]
+    pub status: u32,
+    #[prost(bytes, tag="3")]
+    pub body: std::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+    #[prost(message, repeated, tag="1")]
+    pub results: ::std::vec::Vec<BatchItemResult>,
+}
@@ -0,0 +1,27 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Provider {
+    #[prost(uint32, tag="1")]
+    pub provider_id: u32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(enumeration="CheckType", tag="1")]
+    pub check_type: i32,
+    #[prost(message, optional, tag="2")]
+    pub attributes: ::std::option::Option<super::psa_key_attributes::KeyAttributes>,
+    #[prost(message, optional, tag="3")]
+    pub provider: ::std::option::Option<Provider>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CheckType {
+    /// This default variant should not be used.
+    None = 0,
+    Use = 1,
+    Generate = 2,
+    Import = 3,
+    Derive = 4,
+}
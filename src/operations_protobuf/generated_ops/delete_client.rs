@@ -2,7 +2,20 @@
 pub struct Operation {
     #[prost(string, tag="1")]
     pub client: std::string::String,
+    #[prost(bool, tag="2")]
+    pub dry_run: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KeyDeletionFailure {
+    #[prost(message, optional, tag="1")]
+    pub key: ::std::option::Option<super::list_keys::KeyInfo>,
+    #[prost(uint32, tag="2")]
+    pub status: u32,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Result {
+    #[prost(message, repeated, tag="1")]
+    pub deleted_keys: ::std::vec::Vec<super::list_keys::KeyInfo>,
+    #[prost(message, repeated, tag="2")]
+    pub failures: ::std::vec::Vec<KeyDeletionFailure>,
 }
@@ -0,0 +1,57 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NameAttribute {
+    #[prost(oneof="name_attribute::Attribute", tags="1, 2, 3, 4, 5, 6")]
+    pub attribute: ::std::option::Option<name_attribute::Attribute>,
+}
+pub mod name_attribute {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Attribute {
+        #[prost(string, tag="1")]
+        CommonName(std::string::String),
+        #[prost(string, tag="2")]
+        Organization(std::string::String),
+        #[prost(string, tag="3")]
+        OrganizationalUnit(std::string::String),
+        #[prost(string, tag="4")]
+        Country(std::string::String),
+        #[prost(string, tag="5")]
+        StateOrProvince(std::string::String),
+        #[prost(string, tag="6")]
+        Locality(std::string::String),
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubjectAltName {
+    #[prost(oneof="subject_alt_name::Name", tags="1, 2, 3, 4")]
+    pub name: ::std::option::Option<subject_alt_name::Name>,
+}
+pub mod subject_alt_name {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Name {
+        #[prost(string, tag="1")]
+        DnsName(std::string::String),
+        /// 4 bytes for IPv4 addresses, 16 bytes for IPv6 addresses
+        #[prost(bytes, tag="2")]
+        IpAddress(std::vec::Vec<u8>),
+        #[prost(string, tag="3")]
+        Email(std::string::String),
+        #[prost(string, tag="4")]
+        Uri(std::string::String),
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(string, tag="1")]
+    pub key_name: std::string::String,
+    #[prost(message, repeated, tag="2")]
+    pub subject: ::std::vec::Vec<NameAttribute>,
+    #[prost(message, repeated, tag="3")]
+    pub subject_alt_names: ::std::vec::Vec<SubjectAltName>,
+    #[prost(message, optional, tag="4")]
+    pub alg: ::std::option::Option<super::psa_algorithm::algorithm::AsymmetricSignature>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+    #[prost(bytes, tag="1")]
+    pub csr: std::vec::Vec<u8>,
+}
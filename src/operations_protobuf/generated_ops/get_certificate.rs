@@ -0,0 +1,10 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(string, tag="1")]
+    pub key_name: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+    #[prost(bytes, repeated, tag="1")]
+    pub chain: ::std::vec::Vec<std::vec::Vec<u8>>,
+}
@@ -0,0 +1,8 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+    #[prost(string, repeated, tag="1")]
+    pub key_names: ::std::vec::Vec<std::string::String>,
+}
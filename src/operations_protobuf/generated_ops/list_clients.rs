@@ -1,8 +1,32 @@
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProviderKeyCount {
+    #[prost(uint32, tag="1")]
+    pub provider_id: u32,
+    #[prost(uint32, tag="2")]
+    pub key_count: u32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClientInfo {
+    #[prost(string, tag="1")]
+    pub name: std::string::String,
+    #[prost(message, repeated, tag="2")]
+    pub keys_per_provider: ::std::vec::Vec<ProviderKeyCount>,
+    #[prost(uint64, tag="3")]
+    pub last_seen: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Operation {
+    #[prost(string, tag="1")]
+    pub page_token: std::string::String,
+    #[prost(uint32, tag="2")]
+    pub limit: u32,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Result {
     #[prost(string, repeated, tag="1")]
     pub clients: ::std::vec::Vec<std::string::String>,
+    #[prost(message, repeated, tag="2")]
+    pub client_infos: ::std::vec::Vec<ClientInfo>,
+    #[prost(string, tag="3")]
+    pub next_page_token: std::string::String,
 }
@@ -1,4 +1,9 @@
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KeyLifetime {
+    #[prost(uint32, tag="1")]
+    pub value: u32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct KeyInfo {
     #[prost(uint32, tag="1")]
     pub provider_id: u32,
@@ -6,12 +11,47 @@ pub struct KeyInfo {
     pub name: std::string::String,
     #[prost(message, optional, tag="3")]
     pub attributes: ::std::option::Option<super::psa_key_attributes::KeyAttributes>,
+    #[prost(uint64, tag="4")]
+    pub creation_time: u64,
+    #[prost(enumeration="KeyOrigin", tag="5")]
+    pub origin: i32,
+    #[prost(message, optional, tag="6")]
+    pub lifetime: ::std::option::Option<KeyLifetime>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProviderFilter {
+    #[prost(uint32, tag="1")]
+    pub provider_id: u32,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Operation {
+    #[prost(message, optional, tag="1")]
+    pub provider: ::std::option::Option<ProviderFilter>,
+    #[prost(message, optional, tag="2")]
+    pub key_type: ::std::option::Option<super::psa_key_attributes::KeyType>,
+    #[prost(message, optional, tag="3")]
+    pub usage_flags: ::std::option::Option<super::psa_key_attributes::UsageFlags>,
+    #[prost(string, tag="4")]
+    pub name_prefix: std::string::String,
+    #[prost(string, tag="5")]
+    pub page_token: std::string::String,
+    #[prost(uint32, tag="6")]
+    pub limit: u32,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Result {
     #[prost(message, repeated, tag="1")]
     pub keys: ::std::vec::Vec<KeyInfo>,
+    #[prost(string, tag="2")]
+    pub next_page_token: std::string::String,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum KeyOrigin {
+    /// The origin of the key is not known.
+    None = 0,
+    Generated = 1,
+    Imported = 2,
+    Derived = 3,
+    Copied = 4,
 }
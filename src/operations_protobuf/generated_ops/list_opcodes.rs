@@ -4,7 +4,18 @@ pub struct Operation {
     pub provider_id: u32,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OpcodeInfo {
+    #[prost(uint32, tag="1")]
+    pub opcode: u32,
+    #[prost(uint64, tag="2")]
+    pub max_input_size: u64,
+    #[prost(message, repeated, tag="3")]
+    pub supported_algorithms: ::std::vec::Vec<super::psa_algorithm::Algorithm>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Result {
     #[prost(uint32, repeated, tag="1")]
     pub opcodes: ::std::vec::Vec<u32>,
+    #[prost(message, repeated, tag="2")]
+    pub opcode_info: ::std::vec::Vec<OpcodeInfo>,
 }
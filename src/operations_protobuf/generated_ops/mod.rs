@@ -27,6 +27,35 @@ pub mod psa_generate_random;
 pub mod psa_hash_compute;
 pub mod psa_hash_compare;
 pub mod psa_raw_key_agreement;
+pub mod psa_cipher_encrypt;
+pub mod psa_cipher_decrypt;
+pub mod psa_mac_compute;
+pub mod psa_mac_verify;
+pub mod psa_key_derivation;
+pub mod psa_key_agreement;
+pub mod can_do_crypto;
+pub mod prepare_key_attestation;
+pub mod attest_key;
+pub mod psa_hash_setup;
+pub mod psa_hash_update;
+pub mod psa_hash_finish;
+pub mod psa_hash_abort;
+pub mod psa_aead_encrypt_setup;
+pub mod psa_aead_decrypt_setup;
+pub mod psa_aead_update_ad;
+pub mod psa_aead_update;
+pub mod psa_aead_finish;
+pub mod psa_aead_verify;
+pub mod psa_aead_abort;
+pub mod psa_copy_key;
+pub mod psa_get_key_attributes;
+pub mod batch;
+pub mod attach_certificate;
+pub mod get_certificate;
+pub mod list_certificates;
+pub mod remove_certificate;
+pub mod generate_csr;
+pub mod rotate_key;
 
 use zeroize::Zeroize;
 
@@ -152,6 +181,39 @@ empty_clear_message!(psa_verify_hash::Result);
 empty_clear_message!(psa_verify_message::Result);
 empty_clear_message!(psa_generate_random::Operation);
 empty_clear_message!(psa_hash_compare::Result);
+empty_clear_message!(psa_mac_verify::Result);
+empty_clear_message!(psa_key_agreement::Result);
+empty_clear_message!(can_do_crypto::Operation);
+empty_clear_message!(can_do_crypto::Result);
+empty_clear_message!(prepare_key_attestation::Operation);
+empty_clear_message!(psa_hash_setup::Operation);
+empty_clear_message!(psa_hash_setup::Result);
+empty_clear_message!(psa_hash_update::Result);
+empty_clear_message!(psa_hash_finish::Operation);
+empty_clear_message!(psa_hash_abort::Operation);
+empty_clear_message!(psa_hash_abort::Result);
+empty_clear_message!(psa_aead_encrypt_setup::Result);
+empty_clear_message!(psa_aead_decrypt_setup::Result);
+empty_clear_message!(psa_aead_update_ad::Result);
+empty_clear_message!(psa_aead_finish::Operation);
+empty_clear_message!(psa_aead_abort::Operation);
+empty_clear_message!(psa_aead_abort::Result);
+empty_clear_message!(psa_copy_key::Operation);
+empty_clear_message!(psa_copy_key::Result);
+empty_clear_message!(psa_get_key_attributes::Operation);
+empty_clear_message!(psa_get_key_attributes::Result);
+empty_clear_message!(attach_certificate::Operation);
+empty_clear_message!(attach_certificate::Result);
+empty_clear_message!(get_certificate::Operation);
+empty_clear_message!(get_certificate::Result);
+empty_clear_message!(list_certificates::Operation);
+empty_clear_message!(list_certificates::Result);
+empty_clear_message!(remove_certificate::Operation);
+empty_clear_message!(remove_certificate::Result);
+empty_clear_message!(generate_csr::Operation);
+empty_clear_message!(generate_csr::Result);
+empty_clear_message!(rotate_key::Operation);
+empty_clear_message!(rotate_key::Result);
 
 impl ClearProtoMessage for psa_sign_hash::Operation {
     fn clear_message(&mut self) {
@@ -288,6 +350,187 @@ impl ClearProtoMessage for psa_raw_key_agreement::Result {
     }
 }
 
+impl ClearProtoMessage for psa_cipher_encrypt::Operation {
+    fn clear_message(&mut self) {
+        self.plaintext.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_cipher_encrypt::Result {
+    fn clear_message(&mut self) {
+        self.ciphertext.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_cipher_decrypt::Operation {
+    fn clear_message(&mut self) {
+        self.ciphertext.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_cipher_decrypt::Result {
+    fn clear_message(&mut self) {
+        self.plaintext.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_mac_compute::Operation {
+    fn clear_message(&mut self) {
+        self.input.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_mac_compute::Result {
+    fn clear_message(&mut self) {
+        self.mac.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_mac_verify::Operation {
+    fn clear_message(&mut self) {
+        self.input.zeroize();
+        self.mac.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_key_derivation::Operation {
+    fn clear_message(&mut self) {
+        self.salt.zeroize();
+        self.info.zeroize();
+        self.seed.zeroize();
+        self.label.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_key_derivation::Result {
+    fn clear_message(&mut self) {
+        self.output.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_key_agreement::Operation {
+    fn clear_message(&mut self) {
+        self.peer_key.zeroize();
+    }
+}
+
+impl ClearProtoMessage for attest_key::Operation {
+    fn clear_message(&mut self) {
+        if let Some(attest_key::AttestationMechanismParams {
+            mechanism: Some(attest_key::attestation_mechanism_params::Mechanism::ActivateCredential(
+                attest_key::attestation_mechanism_params::ActivateCredential { credential, secret }
+            ))
+        }) = &mut self.parameters {
+            credential.zeroize();
+            secret.zeroize();
+        }
+    }
+}
+
+impl ClearProtoMessage for attest_key::Result {
+    fn clear_message(&mut self) {
+        if let Some(attest_key::AttestationOutput {
+            mechanism: Some(attest_key::attestation_output::Mechanism::ActivateCredential(
+                attest_key::attestation_output::ActivateCredential { credential }
+            ))
+        }) = &mut self.output {
+            credential.zeroize();
+        }
+    }
+}
+
+impl ClearProtoMessage for prepare_key_attestation::Result {
+    fn clear_message(&mut self) {
+        if let Some(prepare_key_attestation::PrepareKeyAttestationOutput {
+            mechanism: Some(prepare_key_attestation::prepare_key_attestation_output::Mechanism::ActivateCredential(
+                prepare_key_attestation::prepare_key_attestation_output::ActivateCredential { name, public, attesting_key_pub }
+            ))
+        }) = &mut self.output {
+            name.zeroize();
+            public.zeroize();
+            attesting_key_pub.zeroize();
+        }
+    }
+}
+
+impl ClearProtoMessage for psa_hash_update::Operation {
+    fn clear_message(&mut self) {
+        self.input.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_hash_finish::Result {
+    fn clear_message(&mut self) {
+        self.hash.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_aead_encrypt_setup::Operation {
+    fn clear_message(&mut self) {
+        self.nonce.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_aead_decrypt_setup::Operation {
+    fn clear_message(&mut self) {
+        self.nonce.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_aead_update_ad::Operation {
+    fn clear_message(&mut self) {
+        self.additional_data.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_aead_update::Operation {
+    fn clear_message(&mut self) {
+        self.input.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_aead_update::Result {
+    fn clear_message(&mut self) {
+        self.output.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_aead_finish::Result {
+    fn clear_message(&mut self) {
+        self.ciphertext.zeroize();
+        self.tag.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_aead_verify::Operation {
+    fn clear_message(&mut self) {
+        self.tag.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_aead_verify::Result {
+    fn clear_message(&mut self) {
+        self.plaintext.zeroize();
+    }
+}
+
+// The bodies of the batched operations and results may contain sensitive data.
+impl ClearProtoMessage for batch::Operation {
+    fn clear_message(&mut self) {
+        for operation in &mut self.operations {
+            operation.body.zeroize();
+        }
+    }
+}
+
+impl ClearProtoMessage for batch::Result {
+    fn clear_message(&mut self) {
+        for result in &mut self.results {
+            result.body.zeroize();
+        }
+    }
+}
+
 #[test]
 fn i32_conversions() {
     assert_eq!(Cipher::try_from(56).unwrap_err(), ResponseStatus::InvalidEncoding);
@@ -2,6 +2,15 @@
 pub struct Operation {
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WireProtocolVersion {
+    /// Cast down to 8 bits
+    #[prost(uint32, tag="1")]
+    pub major: u32,
+    /// Cast down to 8 bits
+    #[prost(uint32, tag="2")]
+    pub minor: u32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Result {
     /// Cast down to 8 bits
     #[prost(uint32, tag="1")]
@@ -9,4 +18,11 @@ pub struct Result {
     /// Cast down to 8 bits
     #[prost(uint32, tag="2")]
     pub wire_protocol_version_min: u32,
+    #[prost(message, repeated, tag="3")]
+    pub supported_wire_protocol_versions: ::std::vec::Vec<WireProtocolVersion>,
+    /// Cast down to 8 bits
+    #[prost(uint32, repeated, tag="4")]
+    pub supported_body_types: ::std::vec::Vec<u32>,
+    #[prost(uint64, tag="5")]
+    pub max_body_size: u64,
 }
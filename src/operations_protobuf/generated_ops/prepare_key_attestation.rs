@@ -0,0 +1,50 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PrepareKeyAttestationParams {
+    #[prost(oneof="prepare_key_attestation_params::Mechanism", tags="1")]
+    pub mechanism: ::std::option::Option<prepare_key_attestation_params::Mechanism>,
+}
+pub mod prepare_key_attestation_params {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ActivateCredential {
+        #[prost(string, tag="1")]
+        pub attested_key_name: std::string::String,
+        #[prost(string, tag="2")]
+        pub attesting_key_name: std::string::String,
+    }
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Mechanism {
+        #[prost(message, tag="1")]
+        ActivateCredential(ActivateCredential),
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(message, optional, tag="1")]
+    pub parameters: ::std::option::Option<PrepareKeyAttestationParams>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PrepareKeyAttestationOutput {
+    #[prost(oneof="prepare_key_attestation_output::Mechanism", tags="1")]
+    pub mechanism: ::std::option::Option<prepare_key_attestation_output::Mechanism>,
+}
+pub mod prepare_key_attestation_output {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ActivateCredential {
+        #[prost(bytes, tag="1")]
+        pub name: std::vec::Vec<u8>,
+        #[prost(bytes, tag="2")]
+        pub public: std::vec::Vec<u8>,
+        #[prost(bytes, tag="3")]
+        pub attesting_key_pub: std::vec::Vec<u8>,
+    }
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Mechanism {
+        #[prost(message, tag="1")]
+        ActivateCredential(ActivateCredential),
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+    #[prost(message, optional, tag="1")]
+    pub output: ::std::option::Option<PrepareKeyAttestationOutput>,
+}
@@ -0,0 +1,8 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(uint32, tag="1")]
+    pub operation_handle: u32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+}
@@ -0,0 +1,16 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(string, tag="1")]
+    pub key_name: std::string::String,
+    #[prost(message, optional, tag="2")]
+    pub alg: ::std::option::Option<super::psa_algorithm::algorithm::Aead>,
+    #[prost(bytes, tag="3")]
+    pub nonce: std::vec::Vec<u8>,
+    #[prost(message, optional, tag="4")]
+    pub lengths: ::std::option::Option<super::psa_aead_encrypt_setup::Lengths>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+    #[prost(uint32, tag="1")]
+    pub operation_handle: u32,
+}
@@ -0,0 +1,23 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Lengths {
+    #[prost(uint64, tag="1")]
+    pub ad_length: u64,
+    #[prost(uint64, tag="2")]
+    pub plaintext_length: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(string, tag="1")]
+    pub key_name: std::string::String,
+    #[prost(message, optional, tag="2")]
+    pub alg: ::std::option::Option<super::psa_algorithm::algorithm::Aead>,
+    #[prost(bytes, tag="3")]
+    pub nonce: std::vec::Vec<u8>,
+    #[prost(message, optional, tag="4")]
+    pub lengths: ::std::option::Option<Lengths>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+    #[prost(uint32, tag="1")]
+    pub operation_handle: u32,
+}
@@ -0,0 +1,12 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(uint32, tag="1")]
+    pub operation_handle: u32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+    #[prost(bytes, tag="1")]
+    pub ciphertext: std::vec::Vec<u8>,
+    #[prost(bytes, tag="2")]
+    pub tag: std::vec::Vec<u8>,
+}
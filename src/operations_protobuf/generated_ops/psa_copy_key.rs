@@ -0,0 +1,12 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(string, tag="1")]
+    pub key_name: std::string::String,
+    #[prost(string, tag="2")]
+    pub target_key_name: std::string::String,
+    #[prost(message, optional, tag="3")]
+    pub attributes: ::std::option::Option<super::psa_key_attributes::KeyAttributes>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+}
@@ -0,0 +1,10 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(enumeration="super::psa_algorithm::algorithm::Hash", tag="1")]
+    pub alg: i32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+    #[prost(uint32, tag="1")]
+    pub operation_handle: u32,
+}
@@ -0,0 +1,16 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(message, optional, tag="1")]
+    pub alg: ::std::option::Option<super::psa_algorithm::algorithm::KeyAgreement>,
+    #[prost(string, tag="2")]
+    pub private_key_name: std::string::String,
+    #[prost(bytes, tag="3")]
+    pub peer_key: std::vec::Vec<u8>,
+    #[prost(string, tag="4")]
+    pub key_name: std::string::String,
+    #[prost(message, optional, tag="5")]
+    pub attributes: ::std::option::Option<super::psa_key_attributes::KeyAttributes>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+}
@@ -0,0 +1,43 @@
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OutputBytes {
+    #[prost(uint32, tag="1")]
+    pub length: u32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OutputKey {
+    #[prost(string, tag="1")]
+    pub key_name: std::string::String,
+    #[prost(message, optional, tag="2")]
+    pub attributes: ::std::option::Option<super::psa_key_attributes::KeyAttributes>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operation {
+    #[prost(string, tag="1")]
+    pub key_name: std::string::String,
+    #[prost(message, optional, tag="2")]
+    pub alg: ::std::option::Option<super::psa_algorithm::algorithm::KeyDerivation>,
+    #[prost(bytes, tag="3")]
+    pub salt: std::vec::Vec<u8>,
+    #[prost(bytes, tag="4")]
+    pub info: std::vec::Vec<u8>,
+    #[prost(bytes, tag="5")]
+    pub seed: std::vec::Vec<u8>,
+    #[prost(bytes, tag="6")]
+    pub label: std::vec::Vec<u8>,
+    #[prost(oneof="operation::Output", tags="7, 8")]
+    pub output: ::std::option::Option<operation::Output>,
+}
+pub mod operation {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Output {
+        #[prost(message, tag="7")]
+        Bytes(super::OutputBytes),
+        #[prost(message, tag="8")]
+        Key(super::OutputKey),
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Result {
+    #[prost(bytes, tag="1")]
+    pub output: std::vec::Vec<u8>,
+}
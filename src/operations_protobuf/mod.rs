@@ -29,6 +29,35 @@ mod convert_psa_aead_encrypt;
 mod convert_psa_aead_decrypt;
 mod convert_psa_generate_random;
 mod convert_psa_raw_key_agreement;
+mod convert_psa_cipher_encrypt;
+mod convert_psa_cipher_decrypt;
+mod convert_psa_mac_compute;
+mod convert_psa_mac_verify;
+mod convert_psa_key_derivation;
+mod convert_psa_key_agreement;
+mod convert_can_do_crypto;
+mod convert_prepare_key_attestation;
+mod convert_attest_key;
+mod convert_psa_hash_setup;
+mod convert_psa_hash_update;
+mod convert_psa_hash_finish;
+mod convert_psa_hash_abort;
+mod convert_psa_aead_encrypt_setup;
+mod convert_psa_aead_decrypt_setup;
+mod convert_psa_aead_update_ad;
+mod convert_psa_aead_update;
+mod convert_psa_aead_finish;
+mod convert_psa_aead_verify;
+mod convert_psa_aead_abort;
+mod convert_psa_copy_key;
+mod convert_psa_get_key_attributes;
+mod convert_batch;
+mod convert_attach_certificate;
+mod convert_get_certificate;
+mod convert_list_certificates;
+mod convert_remove_certificate;
+mod convert_generate_csr;
+mod convert_rotate_key;
 
 #[rustfmt::skip]
 #[allow(unused_qualifications, missing_copy_implementations, clippy::pedantic, clippy::module_inception, clippy::upper_case_acronyms, clippy::enum_variant_names)]
@@ -38,30 +67,59 @@ use crate::operations::{Convert, NativeOperation, NativeResult};
 use crate::requests::{
     request::RequestBody, response::ResponseBody, BodyType, Opcode, ResponseStatus, Result,
 };
+use generated_ops::attach_certificate as attach_certificate_proto;
+use generated_ops::attest_key as attest_key_proto;
+use generated_ops::batch as batch_proto;
+use generated_ops::can_do_crypto as can_do_crypto_proto;
 use generated_ops::delete_client as delete_client_proto;
+use generated_ops::generate_csr as generate_csr_proto;
+use generated_ops::get_certificate as get_certificate_proto;
 use generated_ops::list_authenticators as list_authenticators_proto;
+use generated_ops::list_certificates as list_certificates_proto;
 use generated_ops::list_clients as list_clients_proto;
 use generated_ops::list_keys as list_keys_proto;
 use generated_ops::list_opcodes as list_opcodes_proto;
 use generated_ops::list_providers as list_providers_proto;
 use generated_ops::ping as ping_proto;
+use generated_ops::prepare_key_attestation as prepare_key_attestation_proto;
+use generated_ops::psa_aead_abort as psa_aead_abort_proto;
 use generated_ops::psa_aead_decrypt as psa_aead_decrypt_proto;
+use generated_ops::psa_aead_decrypt_setup as psa_aead_decrypt_setup_proto;
 use generated_ops::psa_aead_encrypt as psa_aead_encrypt_proto;
+use generated_ops::psa_aead_encrypt_setup as psa_aead_encrypt_setup_proto;
+use generated_ops::psa_aead_finish as psa_aead_finish_proto;
+use generated_ops::psa_aead_update as psa_aead_update_proto;
+use generated_ops::psa_aead_update_ad as psa_aead_update_ad_proto;
+use generated_ops::psa_aead_verify as psa_aead_verify_proto;
 use generated_ops::psa_asymmetric_decrypt as psa_asymmetric_decrypt_proto;
 use generated_ops::psa_asymmetric_encrypt as psa_asymmetric_encrypt_proto;
+use generated_ops::psa_cipher_decrypt as psa_cipher_decrypt_proto;
+use generated_ops::psa_cipher_encrypt as psa_cipher_encrypt_proto;
+use generated_ops::psa_copy_key as psa_copy_key_proto;
 use generated_ops::psa_destroy_key as psa_destroy_key_proto;
 use generated_ops::psa_export_key as psa_export_key_proto;
 use generated_ops::psa_export_public_key as psa_export_public_key_proto;
 use generated_ops::psa_generate_key as psa_generate_key_proto;
 use generated_ops::psa_generate_random as psa_generate_random_proto;
+use generated_ops::psa_get_key_attributes as psa_get_key_attributes_proto;
+use generated_ops::psa_hash_abort as psa_hash_abort_proto;
 use generated_ops::psa_hash_compare as psa_hash_compare_proto;
 use generated_ops::psa_hash_compute as psa_hash_compute_proto;
+use generated_ops::psa_hash_finish as psa_hash_finish_proto;
+use generated_ops::psa_hash_setup as psa_hash_setup_proto;
+use generated_ops::psa_hash_update as psa_hash_update_proto;
 use generated_ops::psa_import_key as psa_import_key_proto;
+use generated_ops::psa_key_agreement as psa_key_agreement_proto;
+use generated_ops::psa_key_derivation as psa_key_derivation_proto;
+use generated_ops::psa_mac_compute as psa_mac_compute_proto;
+use generated_ops::psa_mac_verify as psa_mac_verify_proto;
 use generated_ops::psa_raw_key_agreement as psa_raw_key_agreement_proto;
 use generated_ops::psa_sign_hash as psa_sign_hash_proto;
 use generated_ops::psa_sign_message as psa_sign_message_proto;
 use generated_ops::psa_verify_hash as psa_verify_hash_proto;
 use generated_ops::psa_verify_message as psa_verify_message_proto;
+use generated_ops::remove_certificate as remove_certificate_proto;
+use generated_ops::rotate_key as rotate_key_proto;
 use generated_ops::ClearProtoMessage;
 use prost::Message;
 use std::convert::TryInto;
@@ -195,6 +253,118 @@ impl Convert for ProtobufConverter {
                 body.bytes(),
                 psa_raw_key_agreement_proto::Operation
             ))),
+            Opcode::PsaCipherEncrypt => Ok(NativeOperation::PsaCipherEncrypt(wire_to_native!(
+                body.bytes(),
+                psa_cipher_encrypt_proto::Operation
+            ))),
+            Opcode::PsaCipherDecrypt => Ok(NativeOperation::PsaCipherDecrypt(wire_to_native!(
+                body.bytes(),
+                psa_cipher_decrypt_proto::Operation
+            ))),
+            Opcode::PsaMacCompute => Ok(NativeOperation::PsaMacCompute(wire_to_native!(
+                body.bytes(),
+                psa_mac_compute_proto::Operation
+            ))),
+            Opcode::PsaMacVerify => Ok(NativeOperation::PsaMacVerify(wire_to_native!(
+                body.bytes(),
+                psa_mac_verify_proto::Operation
+            ))),
+            Opcode::PsaKeyDerivation => Ok(NativeOperation::PsaKeyDerivation(wire_to_native!(
+                body.bytes(),
+                psa_key_derivation_proto::Operation
+            ))),
+            Opcode::PsaKeyAgreement => Ok(NativeOperation::PsaKeyAgreement(wire_to_native!(
+                body.bytes(),
+                psa_key_agreement_proto::Operation
+            ))),
+            Opcode::CanDoCrypto => Ok(NativeOperation::CanDoCrypto(wire_to_native!(
+                body.bytes(),
+                can_do_crypto_proto::Operation
+            ))),
+            Opcode::PrepareKeyAttestation => Ok(NativeOperation::PrepareKeyAttestation(
+                wire_to_native!(body.bytes(), prepare_key_attestation_proto::Operation),
+            )),
+            Opcode::AttestKey => Ok(NativeOperation::AttestKey(wire_to_native!(
+                body.bytes(),
+                attest_key_proto::Operation
+            ))),
+            Opcode::PsaHashSetup => Ok(NativeOperation::PsaHashSetup(wire_to_native!(
+                body.bytes(),
+                psa_hash_setup_proto::Operation
+            ))),
+            Opcode::PsaHashUpdate => Ok(NativeOperation::PsaHashUpdate(wire_to_native!(
+                body.bytes(),
+                psa_hash_update_proto::Operation
+            ))),
+            Opcode::PsaHashFinish => Ok(NativeOperation::PsaHashFinish(wire_to_native!(
+                body.bytes(),
+                psa_hash_finish_proto::Operation
+            ))),
+            Opcode::PsaHashAbort => Ok(NativeOperation::PsaHashAbort(wire_to_native!(
+                body.bytes(),
+                psa_hash_abort_proto::Operation
+            ))),
+            Opcode::PsaAeadEncryptSetup => Ok(NativeOperation::PsaAeadEncryptSetup(
+                wire_to_native!(body.bytes(), psa_aead_encrypt_setup_proto::Operation),
+            )),
+            Opcode::PsaAeadDecryptSetup => Ok(NativeOperation::PsaAeadDecryptSetup(
+                wire_to_native!(body.bytes(), psa_aead_decrypt_setup_proto::Operation),
+            )),
+            Opcode::PsaAeadUpdateAd => Ok(NativeOperation::PsaAeadUpdateAd(wire_to_native!(
+                body.bytes(),
+                psa_aead_update_ad_proto::Operation
+            ))),
+            Opcode::PsaAeadUpdate => Ok(NativeOperation::PsaAeadUpdate(wire_to_native!(
+                body.bytes(),
+                psa_aead_update_proto::Operation
+            ))),
+            Opcode::PsaAeadFinish => Ok(NativeOperation::PsaAeadFinish(wire_to_native!(
+                body.bytes(),
+                psa_aead_finish_proto::Operation
+            ))),
+            Opcode::PsaAeadVerify => Ok(NativeOperation::PsaAeadVerify(wire_to_native!(
+                body.bytes(),
+                psa_aead_verify_proto::Operation
+            ))),
+            Opcode::PsaAeadAbort => Ok(NativeOperation::PsaAeadAbort(wire_to_native!(
+                body.bytes(),
+                psa_aead_abort_proto::Operation
+            ))),
+            Opcode::PsaCopyKey => Ok(NativeOperation::PsaCopyKey(wire_to_native!(
+                body.bytes(),
+                psa_copy_key_proto::Operation
+            ))),
+            Opcode::PsaGetKeyAttributes => Ok(NativeOperation::PsaGetKeyAttributes(
+                wire_to_native!(body.bytes(), psa_get_key_attributes_proto::Operation),
+            )),
+            Opcode::Batch => Ok(NativeOperation::Batch(wire_to_native!(
+                body.bytes(),
+                batch_proto::Operation
+            ))),
+            Opcode::AttachCertificate => Ok(NativeOperation::AttachCertificate(wire_to_native!(
+                body.bytes(),
+                attach_certificate_proto::Operation
+            ))),
+            Opcode::GetCertificate => Ok(NativeOperation::GetCertificate(wire_to_native!(
+                body.bytes(),
+                get_certificate_proto::Operation
+            ))),
+            Opcode::ListCertificates => Ok(NativeOperation::ListCertificates(wire_to_native!(
+                body.bytes(),
+                list_certificates_proto::Operation
+            ))),
+            Opcode::RemoveCertificate => Ok(NativeOperation::RemoveCertificate(wire_to_native!(
+                body.bytes(),
+                remove_certificate_proto::Operation
+            ))),
+            Opcode::GenerateCsr => Ok(NativeOperation::GenerateCsr(wire_to_native!(
+                body.bytes(),
+                generate_csr_proto::Operation
+            ))),
+            Opcode::RotateKey => Ok(NativeOperation::RotateKey(wire_to_native!(
+                body.bytes(),
+                rotate_key_proto::Operation
+            ))),
         }
     }
 
@@ -274,6 +444,97 @@ impl Convert for ProtobufConverter {
             NativeOperation::PsaRawKeyAgreement(operation) => Ok(RequestBody::from_bytes(
                 native_to_wire!(operation, psa_raw_key_agreement_proto::Operation),
             )),
+            NativeOperation::PsaCipherEncrypt(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_cipher_encrypt_proto::Operation),
+            )),
+            NativeOperation::PsaCipherDecrypt(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_cipher_decrypt_proto::Operation),
+            )),
+            NativeOperation::PsaMacCompute(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_mac_compute_proto::Operation),
+            )),
+            NativeOperation::PsaMacVerify(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_mac_verify_proto::Operation),
+            )),
+            NativeOperation::PsaKeyDerivation(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_key_derivation_proto::Operation),
+            )),
+            NativeOperation::PsaKeyAgreement(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_key_agreement_proto::Operation),
+            )),
+            NativeOperation::CanDoCrypto(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, can_do_crypto_proto::Operation),
+            )),
+            NativeOperation::PrepareKeyAttestation(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, prepare_key_attestation_proto::Operation),
+            )),
+            NativeOperation::AttestKey(operation) => Ok(RequestBody::from_bytes(native_to_wire!(
+                operation,
+                attest_key_proto::Operation
+            ))),
+            NativeOperation::PsaHashSetup(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_hash_setup_proto::Operation),
+            )),
+            NativeOperation::PsaHashUpdate(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_hash_update_proto::Operation),
+            )),
+            NativeOperation::PsaHashFinish(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_hash_finish_proto::Operation),
+            )),
+            NativeOperation::PsaHashAbort(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_hash_abort_proto::Operation),
+            )),
+            NativeOperation::PsaAeadEncryptSetup(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_aead_encrypt_setup_proto::Operation),
+            )),
+            NativeOperation::PsaAeadDecryptSetup(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_aead_decrypt_setup_proto::Operation),
+            )),
+            NativeOperation::PsaAeadUpdateAd(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_aead_update_ad_proto::Operation),
+            )),
+            NativeOperation::PsaAeadUpdate(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_aead_update_proto::Operation),
+            )),
+            NativeOperation::PsaAeadFinish(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_aead_finish_proto::Operation),
+            )),
+            NativeOperation::PsaAeadVerify(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_aead_verify_proto::Operation),
+            )),
+            NativeOperation::PsaAeadAbort(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_aead_abort_proto::Operation),
+            )),
+            NativeOperation::PsaCopyKey(operation) => Ok(RequestBody::from_bytes(native_to_wire!(
+                operation,
+                psa_copy_key_proto::Operation
+            ))),
+            NativeOperation::PsaGetKeyAttributes(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_get_key_attributes_proto::Operation),
+            )),
+            NativeOperation::Batch(operation) => Ok(RequestBody::from_bytes(native_to_wire!(
+                operation,
+                batch_proto::Operation
+            ))),
+            NativeOperation::AttachCertificate(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, attach_certificate_proto::Operation),
+            )),
+            NativeOperation::GetCertificate(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, get_certificate_proto::Operation),
+            )),
+            NativeOperation::ListCertificates(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, list_certificates_proto::Operation),
+            )),
+            NativeOperation::RemoveCertificate(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, remove_certificate_proto::Operation),
+            )),
+            NativeOperation::GenerateCsr(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, generate_csr_proto::Operation),
+            )),
+            NativeOperation::RotateKey(operation) => Ok(RequestBody::from_bytes(native_to_wire!(
+                operation,
+                rotate_key_proto::Operation
+            ))),
         }
     }
 
@@ -373,6 +634,121 @@ impl Convert for ProtobufConverter {
                 body.bytes(),
                 psa_raw_key_agreement_proto::Result
             ))),
+            Opcode::PsaCipherEncrypt => Ok(NativeResult::PsaCipherEncrypt(wire_to_native!(
+                body.bytes(),
+                psa_cipher_encrypt_proto::Result
+            ))),
+            Opcode::PsaCipherDecrypt => Ok(NativeResult::PsaCipherDecrypt(wire_to_native!(
+                body.bytes(),
+                psa_cipher_decrypt_proto::Result
+            ))),
+            Opcode::PsaMacCompute => Ok(NativeResult::PsaMacCompute(wire_to_native!(
+                body.bytes(),
+                psa_mac_compute_proto::Result
+            ))),
+            Opcode::PsaMacVerify => Ok(NativeResult::PsaMacVerify(wire_to_native!(
+                body.bytes(),
+                psa_mac_verify_proto::Result
+            ))),
+            Opcode::PsaKeyDerivation => Ok(NativeResult::PsaKeyDerivation(wire_to_native!(
+                body.bytes(),
+                psa_key_derivation_proto::Result
+            ))),
+            Opcode::PsaKeyAgreement => Ok(NativeResult::PsaKeyAgreement(wire_to_native!(
+                body.bytes(),
+                psa_key_agreement_proto::Result
+            ))),
+            Opcode::CanDoCrypto => Ok(NativeResult::CanDoCrypto(wire_to_native!(
+                body.bytes(),
+                can_do_crypto_proto::Result
+            ))),
+            Opcode::PrepareKeyAttestation => Ok(NativeResult::PrepareKeyAttestation(
+                wire_to_native!(body.bytes(), prepare_key_attestation_proto::Result),
+            )),
+            Opcode::AttestKey => Ok(NativeResult::AttestKey(wire_to_native!(
+                body.bytes(),
+                attest_key_proto::Result
+            ))),
+            Opcode::PsaHashSetup => Ok(NativeResult::PsaHashSetup(wire_to_native!(
+                body.bytes(),
+                psa_hash_setup_proto::Result
+            ))),
+            Opcode::PsaHashUpdate => Ok(NativeResult::PsaHashUpdate(wire_to_native!(
+                body.bytes(),
+                psa_hash_update_proto::Result
+            ))),
+            Opcode::PsaHashFinish => Ok(NativeResult::PsaHashFinish(wire_to_native!(
+                body.bytes(),
+                psa_hash_finish_proto::Result
+            ))),
+            Opcode::PsaHashAbort => Ok(NativeResult::PsaHashAbort(wire_to_native!(
+                body.bytes(),
+                psa_hash_abort_proto::Result
+            ))),
+            Opcode::PsaAeadEncryptSetup => Ok(NativeResult::PsaAeadEncryptSetup(wire_to_native!(
+                body.bytes(),
+                psa_aead_encrypt_setup_proto::Result
+            ))),
+            Opcode::PsaAeadDecryptSetup => Ok(NativeResult::PsaAeadDecryptSetup(wire_to_native!(
+                body.bytes(),
+                psa_aead_decrypt_setup_proto::Result
+            ))),
+            Opcode::PsaAeadUpdateAd => Ok(NativeResult::PsaAeadUpdateAd(wire_to_native!(
+                body.bytes(),
+                psa_aead_update_ad_proto::Result
+            ))),
+            Opcode::PsaAeadUpdate => Ok(NativeResult::PsaAeadUpdate(wire_to_native!(
+                body.bytes(),
+                psa_aead_update_proto::Result
+            ))),
+            Opcode::PsaAeadFinish => Ok(NativeResult::PsaAeadFinish(wire_to_native!(
+                body.bytes(),
+                psa_aead_finish_proto::Result
+            ))),
+            Opcode::PsaAeadVerify => Ok(NativeResult::PsaAeadVerify(wire_to_native!(
+                body.bytes(),
+                psa_aead_verify_proto::Result
+            ))),
+            Opcode::PsaAeadAbort => Ok(NativeResult::PsaAeadAbort(wire_to_native!(
+                body.bytes(),
+                psa_aead_abort_proto::Result
+            ))),
+            Opcode::PsaCopyKey => Ok(NativeResult::PsaCopyKey(wire_to_native!(
+                body.bytes(),
+                psa_copy_key_proto::Result
+            ))),
+            Opcode::PsaGetKeyAttributes => Ok(NativeResult::PsaGetKeyAttributes(wire_to_native!(
+                body.bytes(),
+                psa_get_key_attributes_proto::Result
+            ))),
+            Opcode::Batch => Ok(NativeResult::Batch(wire_to_native!(
+                body.bytes(),
+                batch_proto::Result
+            ))),
+            Opcode::AttachCertificate => Ok(NativeResult::AttachCertificate(wire_to_native!(
+                body.bytes(),
+                attach_certificate_proto::Result
+            ))),
+            Opcode::GetCertificate => Ok(NativeResult::GetCertificate(wire_to_native!(
+                body.bytes(),
+                get_certificate_proto::Result
+            ))),
+            Opcode::ListCertificates => Ok(NativeResult::ListCertificates(wire_to_native!(
+                body.bytes(),
+                list_certificates_proto::Result
+            ))),
+            Opcode::RemoveCertificate => Ok(NativeResult::RemoveCertificate(wire_to_native!(
+                body.bytes(),
+                remove_certificate_proto::Result
+            ))),
+            Opcode::GenerateCsr => Ok(NativeResult::GenerateCsr(wire_to_native!(
+                body.bytes(),
+                generate_csr_proto::Result
+            ))),
+            Opcode::RotateKey => Ok(NativeResult::RotateKey(wire_to_native!(
+                body.bytes(),
+                rotate_key_proto::Result
+            ))),
         }
     }
 
@@ -467,6 +843,112 @@ impl Convert for ProtobufConverter {
             NativeResult::PsaRawKeyAgreement(result) => Ok(ResponseBody::from_bytes(
                 native_to_wire!(result, psa_raw_key_agreement_proto::Result),
             )),
+            NativeResult::PsaCipherEncrypt(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, psa_cipher_encrypt_proto::Result),
+            )),
+            NativeResult::PsaCipherDecrypt(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, psa_cipher_decrypt_proto::Result),
+            )),
+            NativeResult::PsaMacCompute(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_mac_compute_proto::Result
+            ))),
+            NativeResult::PsaMacVerify(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_mac_verify_proto::Result
+            ))),
+            NativeResult::PsaKeyDerivation(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, psa_key_derivation_proto::Result),
+            )),
+            NativeResult::PsaKeyAgreement(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_key_agreement_proto::Result
+            ))),
+            NativeResult::CanDoCrypto(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                can_do_crypto_proto::Result
+            ))),
+            NativeResult::PrepareKeyAttestation(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, prepare_key_attestation_proto::Result),
+            )),
+            NativeResult::AttestKey(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                attest_key_proto::Result
+            ))),
+            NativeResult::PsaHashSetup(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_hash_setup_proto::Result
+            ))),
+            NativeResult::PsaHashUpdate(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_hash_update_proto::Result
+            ))),
+            NativeResult::PsaHashFinish(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_hash_finish_proto::Result
+            ))),
+            NativeResult::PsaHashAbort(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_hash_abort_proto::Result
+            ))),
+            NativeResult::PsaAeadEncryptSetup(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, psa_aead_encrypt_setup_proto::Result),
+            )),
+            NativeResult::PsaAeadDecryptSetup(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, psa_aead_decrypt_setup_proto::Result),
+            )),
+            NativeResult::PsaAeadUpdateAd(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_aead_update_ad_proto::Result
+            ))),
+            NativeResult::PsaAeadUpdate(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_aead_update_proto::Result
+            ))),
+            NativeResult::PsaAeadFinish(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_aead_finish_proto::Result
+            ))),
+            NativeResult::PsaAeadVerify(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_aead_verify_proto::Result
+            ))),
+            NativeResult::PsaAeadAbort(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_aead_abort_proto::Result
+            ))),
+            NativeResult::PsaCopyKey(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_copy_key_proto::Result
+            ))),
+            NativeResult::PsaGetKeyAttributes(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, psa_get_key_attributes_proto::Result),
+            )),
+            NativeResult::Batch(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                batch_proto::Result
+            ))),
+            NativeResult::AttachCertificate(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, attach_certificate_proto::Result),
+            )),
+            NativeResult::GetCertificate(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                get_certificate_proto::Result
+            ))),
+            NativeResult::ListCertificates(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, list_certificates_proto::Result),
+            )),
+            NativeResult::RemoveCertificate(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, remove_certificate_proto::Result),
+            )),
+            NativeResult::GenerateCsr(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                generate_csr_proto::Result
+            ))),
+            NativeResult::RotateKey(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                rotate_key_proto::Result
+            ))),
         }
     }
 }
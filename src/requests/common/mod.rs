@@ -2,7 +2,577 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! This module implements the raw wire protocol header frame for requests and responses in
-//! all defined versions of the protocol (currently just 1.0).
+//! all defined versions of the protocol (currently 1.0 and 1.1).
+//!
+//! The version of the header is chosen per connection: a client can find the versions supported
+//! by the service with the Ping operation and pick the highest one it also supports, the service
+//! then replies using the version of the request.
 pub mod wire_header_1_0;
+pub mod wire_header_1_1;
+
+use crate::requests::{ReadLimits, ResponseStatus, Result};
+#[cfg(feature = "fuzz")]
+use arbitrary::Arbitrary;
+use log::error;
+use std::convert::TryFrom;
+use std::io::{Read, Write};
+#[cfg(feature = "async")]
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 
 const MAGIC_NUMBER: u32 = 0x5EC0_A710;
+
+/// Size of the magic number and header size fields, which precede the rest of the header.
+pub(crate) const HDR_PREFIX_SIZE: usize = 6;
+
+/// Version of the wire protocol, represented as `x.y` where `x` is the version major and `y` the
+/// version minor.
+#[cfg_attr(feature = "fuzz", derive(Arbitrary))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WireProtocolVersion {
+    /// Version major
+    pub major: u8,
+    /// Version minor
+    pub minor: u8,
+}
+
+impl WireProtocolVersion {
+    /// Version 1.0 of the wire protocol.
+    pub const V1_0: WireProtocolVersion = WireProtocolVersion { major: 1, minor: 0 };
+    /// Version 1.1 of the wire protocol.
+    pub const V1_1: WireProtocolVersion = WireProtocolVersion { major: 1, minor: 1 };
+}
+
+/// The version 1.0 of the wire protocol is understood by all deployed clients and services.
+impl Default for WireProtocolVersion {
+    fn default() -> Self {
+        WireProtocolVersion::V1_0
+    }
+}
+
+/// Wire protocol versions that can be parsed and emitted by this crate.
+pub const SUPPORTED_WIRE_PROTOCOL_VERSIONS: [WireProtocolVersion; 2] =
+    [WireProtocolVersion::V1_0, WireProtocolVersion::V1_1];
+
+/// Raw representation of a request/response header in any of the supported versions of the
+/// wire protocol.
+#[cfg_attr(feature = "fuzz", derive(Arbitrary))]
+#[derive(Copy, Clone, Debug)]
+pub enum WireHeader {
+    /// Header of version 1.0 of the protocol.
+    V1_0(wire_header_1_0::WireHeader),
+    /// Header of version 1.1 of the protocol.
+    V1_1(wire_header_1_1::WireHeader),
+}
+
+impl WireHeader {
+    /// Version of the wire protocol used by this header.
+    pub fn version(&self) -> WireProtocolVersion {
+        match self {
+            WireHeader::V1_0(_) => WireProtocolVersion::V1_0,
+            WireHeader::V1_1(_) => WireProtocolVersion::V1_1,
+        }
+    }
+
+    /// Number of bytes of content.
+    pub fn body_len(&self) -> u32 {
+        match self {
+            WireHeader::V1_0(header) => header.body_len,
+            WireHeader::V1_1(header) => header.body_len,
+        }
+    }
+
+    /// Number of bytes of the header, including the magic number and header size fields.
+    pub fn hdr_len(&self) -> usize {
+        let hdr_size = match self {
+            WireHeader::V1_0(_) => wire_header_1_0::REQUEST_HDR_SIZE,
+            WireHeader::V1_1(_) => wire_header_1_1::REQUEST_HDR_SIZE,
+        };
+        HDR_PREFIX_SIZE + usize::from(hdr_size)
+    }
+
+    /// Number of bytes of content and authentication following the header, checked against the
+    /// given limits.
+    ///
+    /// The authentication field is only counted if `with_auth` is set, as only requests
+    /// contain one.
+    ///
+    /// # Errors
+    /// - if the number of bytes of content is larger than the limit,
+    ///   `ResponseStatus::BodySizeExceedsLimit` is returned.
+    /// - if the number of bytes of authentication is larger than the limit,
+    ///   `ResponseStatus::AuthSizeExceedsLimit` is returned.
+    /// - if the number of bytes of the whole frame is larger than the limit,
+    ///   `ResponseStatus::FrameSizeExceedsLimit` is returned.
+    pub(crate) fn checked_payload_len(
+        &self,
+        limits: &ReadLimits,
+        with_auth: bool,
+    ) -> Result<(usize, usize)> {
+        let body_len = usize::try_from(self.body_len())?;
+        if body_len > limits.body_len {
+            error!(
+                "Body length ({}) bigger than the limit given ({}).",
+                body_len, limits.body_len
+            );
+            return Err(ResponseStatus::BodySizeExceedsLimit);
+        }
+
+        let auth_len = if with_auth {
+            usize::from(self.auth_len())
+        } else {
+            0
+        };
+        if auth_len > limits.auth_len {
+            error!(
+                "Authentication length ({}) bigger than the limit given ({}).",
+                auth_len, limits.auth_len
+            );
+            return Err(ResponseStatus::AuthSizeExceedsLimit);
+        }
+
+        let frame_len = self
+            .hdr_len()
+            .checked_add(body_len)
+            .and_then(|len| len.checked_add(auth_len))
+            .ok_or_else(|| {
+                error!("Frame length overflows");
+                ResponseStatus::FrameSizeExceedsLimit
+            })?;
+        if frame_len > limits.frame_len {
+            error!(
+                "Frame length ({}) bigger than the limit given ({}).",
+                frame_len, limits.frame_len
+            );
+            return Err(ResponseStatus::FrameSizeExceedsLimit);
+        }
+
+        Ok((body_len, auth_len))
+    }
+
+    /// Set the number of bytes of content.
+    pub fn set_body_len(&mut self, body_len: u32) {
+        match self {
+            WireHeader::V1_0(header) => header.body_len = body_len,
+            WireHeader::V1_1(header) => header.body_len = body_len,
+        }
+    }
+
+    /// Number of bytes of authentication.
+    pub fn auth_len(&self) -> u16 {
+        match self {
+            WireHeader::V1_0(header) => header.auth_len,
+            WireHeader::V1_1(header) => header.auth_len,
+        }
+    }
+
+    /// Set the number of bytes of authentication.
+    pub fn set_auth_len(&mut self, auth_len: u16) {
+        match self {
+            WireHeader::V1_0(header) => header.auth_len = auth_len,
+            WireHeader::V1_1(header) => header.auth_len = auth_len,
+        }
+    }
+
+    /// Serialise the header in its version and write the corresponding bytes to the given
+    /// stream.
+    ///
+    /// # Errors
+    /// - if marshalling the header fails, `ResponseStatus::InvalidEncoding` is returned.
+    /// - if writing the header bytes fails, `ResponseStatus::ConnectionError` is returned.
+    pub fn write_to_stream<W: Write>(&self, stream: &mut W) -> Result<()> {
+        match self {
+            WireHeader::V1_0(header) => header.write_to_stream(stream),
+            WireHeader::V1_1(header) => header.write_to_stream(stream),
+        }
+    }
+
+    /// Deserialise a header of any supported version from the given stream.
+    ///
+    /// # Errors
+    /// - if either the magic number, the header size or the reserved fields
+    ///   are invalid values, `ResponseStatus::InvalidHeader` is returned.
+    /// - if reading the fields after magic number and header size fails,
+    ///   `ResponseStatus::ConnectionError` is returned
+    ///     - the read may fail due to a timeout if not enough bytes are
+    ///       sent across
+    /// - if the parsed bytes cannot be unmarshalled into the contained fields,
+    ///   `ResponseStatus::InvalidEncoding` is returned.
+    /// - if the wire protocol version used is not supported,
+    ///   `ResponseStatus::WireProtocolVersionNotSupported` is returned.
+    pub fn read_from_stream<R: Read>(mut stream: &mut R) -> Result<WireHeader> {
+        let magic_number = get_from_stream!(stream, u32);
+        check_magic_number(magic_number)?;
+
+        let hdr_size = get_from_stream!(stream, u16);
+        let bytes = get_from_stream!(stream; usize::from(hdr_size));
+
+        WireHeader::from_bytes(&bytes)
+    }
+
+    /// Parse a header of any supported version from the start of the given bytes, without
+    /// copying them.
+    ///
+    /// Returns the header and the number of bytes it spans.
+    ///
+    /// # Errors
+    /// - if the bytes are too short to contain the header, `ResponseStatus::InvalidEncoding` is
+    ///   returned.
+    /// - otherwise, the same errors as `read_from_stream` are returned.
+    pub fn parse(bytes: &[u8]) -> Result<(WireHeader, usize)> {
+        if bytes.len() < HDR_PREFIX_SIZE {
+            error!("Buffer of {} bytes too short for a header", bytes.len());
+            return Err(ResponseStatus::InvalidEncoding);
+        }
+        check_magic_number(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))?;
+
+        let hdr_len = HDR_PREFIX_SIZE + usize::from(u16::from_le_bytes([bytes[4], bytes[5]]));
+        if bytes.len() < hdr_len {
+            error!(
+                "Buffer of {} bytes too short for a header of {} bytes",
+                bytes.len(),
+                hdr_len
+            );
+            return Err(ResponseStatus::InvalidEncoding);
+        }
+
+        Ok((
+            WireHeader::from_bytes(&bytes[HDR_PREFIX_SIZE..hdr_len])?,
+            hdr_len,
+        ))
+    }
+
+    /// Serialise the header in its version and write the corresponding bytes to the given
+    /// asynchronous stream.
+    ///
+    /// # Errors
+    /// - if marshalling the header fails, `ResponseStatus::InvalidEncoding` is returned.
+    /// - if writing the header bytes fails, `ResponseStatus::ConnectionError` is returned.
+    #[cfg(feature = "async")]
+    pub async fn write_to_async_stream<W: AsyncWrite + Unpin>(&self, stream: &mut W) -> Result<()> {
+        let mut bytes = Vec::new();
+        self.write_to_stream(&mut bytes)?;
+        stream.write_all(&bytes).await?;
+
+        Ok(())
+    }
+
+    /// Deserialise a header of any supported version from the given asynchronous stream.
+    ///
+    /// # Errors
+    /// The same errors as `read_from_stream` are returned.
+    #[cfg(feature = "async")]
+    pub async fn read_from_async_stream<R: AsyncRead + Unpin>(
+        stream: &mut R,
+    ) -> Result<WireHeader> {
+        let magic_number = stream.read_u32_le().await?;
+        check_magic_number(magic_number)?;
+
+        let hdr_size = stream.read_u16_le().await?;
+        let mut bytes = vec![0_u8; usize::from(hdr_size)];
+        let _ = stream.read_exact(&mut bytes).await?;
+
+        WireHeader::from_bytes(&bytes)
+    }
+
+    /// Check the fields preceding the contents of the header at the start of the given bytes,
+    /// as soon as they are available, without waiting for the whole header.
+    ///
+    /// Returns the number of bytes of the header, or `None` if the version is not available yet.
+    ///
+    /// # Errors
+    /// The same errors as `read_from_stream` are returned for the magic number, the header size
+    /// and the version.
+    pub(crate) fn check_prefix(bytes: &[u8]) -> Result<Option<usize>> {
+        if bytes.len() >= 4 {
+            check_magic_number(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))?;
+        }
+        if bytes.len() < HDR_PREFIX_SIZE {
+            return Ok(None);
+        }
+        let hdr_size = usize::from(u16::from_le_bytes([bytes[4], bytes[5]]));
+        if hdr_size < 2 {
+            error!("Header size {} is too small to contain a version", hdr_size);
+            return Err(ResponseStatus::InvalidHeader);
+        }
+        if bytes.len() < HDR_PREFIX_SIZE + 2 {
+            return Ok(None);
+        }
+        let version = WireProtocolVersion {
+            major: bytes[HDR_PREFIX_SIZE],
+            minor: bytes[HDR_PREFIX_SIZE + 1],
+        };
+        check_hdr_size(version, hdr_size)?;
+
+        Ok(Some(HDR_PREFIX_SIZE + hdr_size))
+    }
+
+    /// Deserialise a header from the bytes following the header size, whose length is the
+    /// header size.
+    fn from_bytes(bytes: &[u8]) -> Result<WireHeader> {
+        let hdr_size = bytes.len();
+        if hdr_size < 2 {
+            error!("Header size {} is too small to contain a version", hdr_size);
+            return Err(ResponseStatus::InvalidHeader);
+        }
+
+        // The first two bytes after the header length are the version major and minor.
+        let version = WireProtocolVersion {
+            major: bytes[0],
+            minor: bytes[1],
+        };
+        check_hdr_size(version, hdr_size)?;
+
+        if version == WireProtocolVersion::V1_0 {
+            Ok(WireHeader::V1_0(wire_header_1_0::WireHeader::from_bytes(
+                &bytes[2..],
+            )?))
+        } else {
+            Ok(WireHeader::V1_1(wire_header_1_1::WireHeader::from_bytes(
+                &bytes[2..],
+            )?))
+        }
+    }
+}
+
+/// Check that the version is supported and that the header size is the one of this version.
+fn check_hdr_size(version: WireProtocolVersion, hdr_size: usize) -> Result<()> {
+    let expected_hdr_size = match version {
+        WireProtocolVersion::V1_0 => wire_header_1_0::REQUEST_HDR_SIZE,
+        WireProtocolVersion::V1_1 => wire_header_1_1::REQUEST_HDR_SIZE,
+        _ => {
+            error!(
+                "Wire protocol version {}.{} is not supported",
+                version.major, version.minor
+            );
+            return Err(ResponseStatus::WireProtocolVersionNotSupported);
+        }
+    };
+    if hdr_size != usize::from(expected_hdr_size) {
+        error!(
+            "Expected request header size {}, got {}",
+            expected_hdr_size, hdr_size
+        );
+        return Err(ResponseStatus::InvalidHeader);
+    }
+    Ok(())
+}
+
+fn check_magic_number(magic_number: u32) -> Result<()> {
+    if magic_number != MAGIC_NUMBER {
+        error!(
+            "Expected magic number {}, got {}",
+            MAGIC_NUMBER, magic_number
+        );
+        return Err(ResponseStatus::InvalidHeader);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::utils::tests as test_utils;
+    use super::*;
+
+    fn get_header_1_1() -> wire_header_1_1::WireHeader {
+        wire_header_1_1::WireHeader {
+            flags: wire_header_1_1::FLAG_KEEP_ALIVE,
+            provider: 1,
+            session: 0x11_22_33_44_55_66_77_88,
+            content_type: 0,
+            accept_type: 0,
+            auth_type: 1,
+            body_len: 3,
+            auth_len: 4,
+            opcode: 1,
+            status: 0,
+            request_id: 0xAA_BB_CC_DD_EE_FF_00_11,
+            reserved1: 0,
+            reserved2: 0,
+        }
+    }
+
+    #[test]
+    fn header_1_1_round_trip() {
+        let mut mock = test_utils::MockReadWrite { buffer: Vec::new() };
+        WireHeader::V1_1(get_header_1_1())
+            .write_to_stream(&mut mock)
+            .expect("Failed to write header");
+        assert_eq!(mock.buffer.len(), 6 + 38);
+        assert_eq!(&mock.buffer[4..8], &[38, 0, 1, 1]);
+
+        let header = WireHeader::read_from_stream(&mut mock).expect("Failed to read header");
+        assert_eq!(header.version(), WireProtocolVersion::V1_1);
+        assert_eq!(header.body_len(), 3);
+        assert_eq!(header.auth_len(), 4);
+        match header {
+            WireHeader::V1_1(header) => {
+                assert!(header.keep_alive());
+                assert_eq!(header.request_id, 0xAA_BB_CC_DD_EE_FF_00_11);
+            }
+            _ => panic!("Expected a version 1.1 header"),
+        }
+    }
+
+    #[test]
+    fn header_1_0_round_trip() {
+        let mut mock = test_utils::MockReadWrite { buffer: Vec::new() };
+        let mut header = WireHeader::V1_0(get_header_1_1().into());
+        header.set_body_len(0x10);
+        header.set_auth_len(0x20);
+        header
+            .write_to_stream(&mut mock)
+            .expect("Failed to write header");
+        assert_eq!(mock.buffer.len(), 6 + 30);
+
+        let header = WireHeader::read_from_stream(&mut mock).expect("Failed to read header");
+        assert_eq!(header.version(), WireProtocolVersion::V1_0);
+        assert_eq!(header.body_len(), 0x10);
+        assert_eq!(header.auth_len(), 0x20);
+    }
+
+    #[test]
+    fn header_1_1_only_readable_as_versioned() {
+        let mut mock = test_utils::MockReadWrite { buffer: Vec::new() };
+        WireHeader::V1_1(get_header_1_1())
+            .write_to_stream(&mut mock)
+            .expect("Failed to write header");
+
+        assert_eq!(
+            wire_header_1_0::WireHeader::read_from_stream(&mut mock).unwrap_err(),
+            ResponseStatus::WireProtocolVersionNotSupported
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn header_async_round_trip() {
+        let mut bytes = Vec::new();
+        WireHeader::V1_1(get_header_1_1())
+            .write_to_async_stream(&mut bytes)
+            .await
+            .expect("Failed to write header");
+
+        let mut sync_bytes = Vec::new();
+        WireHeader::V1_1(get_header_1_1())
+            .write_to_stream(&mut sync_bytes)
+            .expect("Failed to write header");
+        assert_eq!(bytes, sync_bytes);
+
+        let header = WireHeader::read_from_async_stream(&mut bytes.as_slice())
+            .await
+            .expect("Failed to read header");
+        assert_eq!(header.version(), WireProtocolVersion::V1_1);
+        assert_eq!(header.body_len(), 3);
+
+        bytes[0] = 0;
+        assert_eq!(
+            WireHeader::read_from_async_stream(&mut bytes.as_slice())
+                .await
+                .unwrap_err(),
+            ResponseStatus::InvalidHeader
+        );
+    }
+
+    #[test]
+    fn parse_header() {
+        let mut bytes = Vec::new();
+        WireHeader::V1_1(get_header_1_1())
+            .write_to_stream(&mut bytes)
+            .expect("Failed to write header");
+        bytes.extend_from_slice(&[0x11, 0x22]);
+
+        let (header, hdr_len) = WireHeader::parse(&bytes).expect("Failed to parse header");
+        assert_eq!(hdr_len, 6 + 38);
+        assert_eq!(header.version(), WireProtocolVersion::V1_1);
+        assert_eq!(header.auth_len(), 4);
+
+        assert_eq!(
+            WireHeader::parse(&bytes[..hdr_len - 1]).unwrap_err(),
+            ResponseStatus::InvalidEncoding
+        );
+        assert_eq!(
+            WireHeader::parse(&bytes[..5]).unwrap_err(),
+            ResponseStatus::InvalidEncoding
+        );
+        bytes[3] = 0;
+        assert_eq!(
+            WireHeader::parse(&bytes).unwrap_err(),
+            ResponseStatus::InvalidHeader
+        );
+    }
+
+    #[test]
+    fn payload_limits() {
+        let header = WireHeader::V1_1(get_header_1_1());
+        let limits = ReadLimits {
+            body_len: 3,
+            auth_len: 4,
+            frame_len: 44 + 3 + 4,
+        };
+        assert_eq!(header.hdr_len(), 44);
+        assert_eq!(header.checked_payload_len(&limits, true).unwrap(), (3, 4));
+        assert_eq!(header.checked_payload_len(&limits, false).unwrap(), (3, 0));
+
+        let mut small_limits = limits;
+        small_limits.body_len = 2;
+        assert_eq!(
+            header.checked_payload_len(&small_limits, true).unwrap_err(),
+            ResponseStatus::BodySizeExceedsLimit
+        );
+
+        let mut small_limits = limits;
+        small_limits.auth_len = 3;
+        assert_eq!(
+            header.checked_payload_len(&small_limits, true).unwrap_err(),
+            ResponseStatus::AuthSizeExceedsLimit
+        );
+        // Responses do not have an authentication field.
+        assert!(header.checked_payload_len(&small_limits, false).is_ok());
+
+        let mut small_limits = limits;
+        small_limits.frame_len -= 1;
+        assert_eq!(
+            header.checked_payload_len(&small_limits, true).unwrap_err(),
+            ResponseStatus::FrameSizeExceedsLimit
+        );
+        assert!(header.checked_payload_len(&small_limits, false).is_ok());
+    }
+
+    #[test]
+    fn invalid_headers() {
+        let mut valid = test_utils::MockReadWrite { buffer: Vec::new() };
+        WireHeader::V1_1(get_header_1_1())
+            .write_to_stream(&mut valid)
+            .expect("Failed to write header");
+
+        // Size of a 1.0 header with a 1.1 version.
+        let mut mock = test_utils::MockReadWrite {
+            buffer: valid.buffer.clone(),
+        };
+        mock.buffer[4] = 30;
+        assert_eq!(
+            WireHeader::read_from_stream(&mut mock).unwrap_err(),
+            ResponseStatus::InvalidHeader
+        );
+
+        // Unknown flag.
+        let mut mock = test_utils::MockReadWrite {
+            buffer: valid.buffer.clone(),
+        };
+        mock.buffer[8] = 0x02;
+        assert_eq!(
+            WireHeader::read_from_stream(&mut mock).unwrap_err(),
+            ResponseStatus::InvalidHeader
+        );
+
+        // Unknown version.
+        let mut mock = test_utils::MockReadWrite {
+            buffer: valid.buffer,
+        };
+        mock.buffer[7] = 2;
+        assert_eq!(
+            WireHeader::read_from_stream(&mut mock).unwrap_err(),
+            ResponseStatus::WireProtocolVersionNotSupported
+        );
+    }
+}
@@ -3,19 +3,20 @@
 
 //! This module defines and implements the raw wire protocol header frame for
 //! version 1.0 of the protocol.
-use crate::requests::common::MAGIC_NUMBER;
+use crate::requests::common::{WireHeader as VersionedWireHeader, MAGIC_NUMBER};
 use crate::requests::{ResponseStatus, Result};
 #[cfg(feature = "fuzz")]
 use arbitrary::Arbitrary;
 use log::error;
 use serde::{Deserialize, Serialize};
-use std::convert::TryFrom;
 use std::io::{Read, Write};
+#[cfg(feature = "async")]
+use tokio::io::{AsyncRead, AsyncWrite};
 
 const WIRE_PROTOCOL_VERSION_MAJ: u8 = 1;
 const WIRE_PROTOCOL_VERSION_MIN: u8 = 0;
 
-const REQUEST_HDR_SIZE: u16 = 30;
+pub(super) const REQUEST_HDR_SIZE: u16 = 30;
 
 /// Raw representation of a common request/response header, as defined for the wire format.
 ///
@@ -96,46 +97,67 @@ impl WireHeader {
     ///
     /// # Errors
     /// - if either the magic number, the header size or the reserved fields
-    /// are invalid values, `ResponseStatus::InvalidHeader` is returned.
+    ///   are invalid values, `ResponseStatus::InvalidHeader` is returned.
     /// - if reading the fields after magic number and header size fails,
-    /// `ResponseStatus::ConnectionError` is returned
+    ///   `ResponseStatus::ConnectionError` is returned
     ///     - the read may fail due to a timeout if not enough bytes are
-    ///     sent across
+    ///       sent across
     /// - if the parsed bytes cannot be unmarshalled into the contained fields,
-    /// `ResponseStatus::InvalidEncoding` is returned.
-    /// - if the wire protocol version used is different than 1.0
-    pub fn read_from_stream<R: Read>(mut stream: &mut R) -> Result<WireHeader> {
-        let magic_number = get_from_stream!(stream, u32);
-        if magic_number != MAGIC_NUMBER {
-            error!(
-                "Expected magic number {}, got {}",
-                MAGIC_NUMBER, magic_number
-            );
-            return Err(ResponseStatus::InvalidHeader);
-        }
+    ///   `ResponseStatus::InvalidEncoding` is returned.
+    /// - if the wire protocol version used is different than 1.0,
+    ///   `ResponseStatus::WireProtocolVersionNotSupported` is returned.
+    pub fn read_from_stream<R: Read>(stream: &mut R) -> Result<WireHeader> {
+        WireHeader::from_versioned(VersionedWireHeader::read_from_stream(stream)?)
+    }
 
-        let hdr_size = get_from_stream!(stream, u16);
-        let mut bytes = vec![0_u8; usize::try_from(hdr_size)?];
-        stream.read_exact(&mut bytes)?;
-        if hdr_size != REQUEST_HDR_SIZE {
-            error!(
-                "Expected request header size {}, got {}",
-                REQUEST_HDR_SIZE, hdr_size
-            );
-            return Err(ResponseStatus::InvalidHeader);
-        }
+    /// Serialise the request header and write the corresponding bytes to the given
+    /// asynchronous stream.
+    ///
+    /// # Errors
+    /// The same errors as `write_to_stream` are returned.
+    #[cfg(feature = "async")]
+    pub async fn write_to_async_stream<W: AsyncWrite + Unpin>(&self, stream: &mut W) -> Result<()> {
+        VersionedWireHeader::V1_0(*self)
+            .write_to_async_stream(stream)
+            .await
+    }
 
-        let version_maj = bytes.remove(0); // first byte after hdr length is version maj
-        let version_min = bytes.remove(0); // second byte after hdr length is version min
-        if version_maj != WIRE_PROTOCOL_VERSION_MAJ || version_min != WIRE_PROTOCOL_VERSION_MIN {
-            error!(
-                "Expected wire protocol version {}.{}, got {}.{} instead",
-                WIRE_PROTOCOL_VERSION_MAJ, WIRE_PROTOCOL_VERSION_MIN, version_maj, version_min
-            );
-            return Err(ResponseStatus::WireProtocolVersionNotSupported);
+    /// Deserialise a request header from the given asynchronous stream.
+    ///
+    /// # Errors
+    /// The same errors as `read_from_stream` are returned.
+    #[cfg(feature = "async")]
+    pub async fn read_from_async_stream<R: AsyncRead + Unpin>(
+        stream: &mut R,
+    ) -> Result<WireHeader> {
+        WireHeader::from_versioned(VersionedWireHeader::read_from_async_stream(stream).await?)
+    }
+
+    fn from_versioned(wire_header: VersionedWireHeader) -> Result<WireHeader> {
+        match wire_header {
+            VersionedWireHeader::V1_0(wire_header) => Ok(wire_header),
+            wire_header => {
+                let version = wire_header.version();
+                error!(
+                    "Expected wire protocol version {}.{}, got {}.{} instead",
+                    WIRE_PROTOCOL_VERSION_MAJ,
+                    WIRE_PROTOCOL_VERSION_MIN,
+                    version.major,
+                    version.minor
+                );
+                Err(ResponseStatus::WireProtocolVersionNotSupported)
+            }
         }
+    }
 
-        let wire_header: WireHeader = bincode::deserialize(&bytes)?;
+    /// Deserialise the fields of a request header, following the version major and minor.
+    ///
+    /// # Errors
+    /// - if the reserved fields are not zero, `ResponseStatus::InvalidHeader` is returned.
+    /// - if the bytes cannot be unmarshalled into the contained fields,
+    ///   `ResponseStatus::InvalidEncoding` is returned.
+    pub(super) fn from_bytes(bytes: &[u8]) -> Result<WireHeader> {
+        let wire_header: WireHeader = bincode::deserialize(bytes)?;
 
         if wire_header.reserved1 != 0x00 || wire_header.reserved2 != 0x00 {
             Err(ResponseStatus::InvalidHeader)
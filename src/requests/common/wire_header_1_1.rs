@@ -0,0 +1,148 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module defines and implements the raw wire protocol header frame for
+//! version 1.1 of the protocol.
+//!
+//! Compared to version 1.0, the header carries a request identifier chosen by the client and
+//! echoed back by the service, and the `flags` field is given a meaning.
+use super::wire_header_1_0::WireHeader as WireHeader1_0;
+use crate::requests::common::MAGIC_NUMBER;
+use crate::requests::{ResponseStatus, Result};
+#[cfg(feature = "fuzz")]
+use arbitrary::Arbitrary;
+use log::error;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+const WIRE_PROTOCOL_VERSION_MAJ: u8 = 1;
+const WIRE_PROTOCOL_VERSION_MIN: u8 = 1;
+
+pub(super) const REQUEST_HDR_SIZE: u16 = 38;
+
+/// Flag set when the peer intends to keep the connection open for further requests.
+pub const FLAG_KEEP_ALIVE: u16 = 0x0001;
+
+const SUPPORTED_FLAGS: u16 = FLAG_KEEP_ALIVE;
+
+/// Raw representation of a common request/response header, as defined for the wire format.
+///
+/// Serialisation and deserialisation are handled by `serde`, also in tune with the
+/// wire format (i.e. little-endian, native encoding).
+#[cfg_attr(feature = "fuzz", derive(Arbitrary))]
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+pub struct WireHeader {
+    /// Bit field of header flags. Only the bits defined in this module may be set.
+    pub flags: u16,
+    /// Provider ID value
+    pub provider: u8,
+    /// Session handle
+    pub session: u64,
+    /// Content type: defines how the request body should be processed.
+    pub content_type: u8,
+    /// Accept type: defines how the service should provide its response.
+    pub accept_type: u8,
+    /// Authentication type.
+    pub auth_type: u8,
+    /// Number of bytes of content.
+    pub body_len: u32,
+    /// Number of bytes of authentication.
+    pub auth_len: u16,
+    /// Opcode of the operation to perform.
+    pub opcode: u32,
+    /// Response status of the request.
+    pub status: u16,
+    /// Identifier of the request, chosen by the client and copied in the response.
+    pub request_id: u64,
+    /// Reserved byte. Currently unused. Must be present. Must be zero.
+    pub reserved1: u8,
+    /// Reserved byte. Currently unused. Must be present. Must be zero.
+    pub reserved2: u8,
+}
+
+impl WireHeader {
+    /// Create a version 1.1 header from the fields of a version 1.0 one and the request
+    /// identifier.
+    pub fn from_1_0(header: WireHeader1_0, request_id: u64) -> WireHeader {
+        WireHeader {
+            flags: header.flags,
+            provider: header.provider,
+            session: header.session,
+            content_type: header.content_type,
+            accept_type: header.accept_type,
+            auth_type: header.auth_type,
+            body_len: header.body_len,
+            auth_len: header.auth_len,
+            opcode: header.opcode,
+            status: header.status,
+            request_id,
+            reserved1: header.reserved1,
+            reserved2: header.reserved2,
+        }
+    }
+
+    /// Check if the `FLAG_KEEP_ALIVE` flag is set.
+    pub fn keep_alive(&self) -> bool {
+        self.flags & FLAG_KEEP_ALIVE != 0
+    }
+
+    /// Serialise the request header and write the corresponding bytes to the given
+    /// stream.
+    ///
+    /// # Errors
+    /// - if marshalling the header fails, `ResponseStatus::InvalidEncoding` is returned.
+    /// - if writing the header bytes fails, `ResponseStatus::ConnectionError` is returned.
+    pub fn write_to_stream<W: Write>(&self, stream: &mut W) -> Result<()> {
+        stream.write_all(&bincode::serialize(&MAGIC_NUMBER)?)?;
+
+        stream.write_all(&bincode::serialize(&REQUEST_HDR_SIZE)?)?;
+
+        stream.write_all(&bincode::serialize(&WIRE_PROTOCOL_VERSION_MAJ)?)?;
+        stream.write_all(&bincode::serialize(&WIRE_PROTOCOL_VERSION_MIN)?)?;
+
+        stream.write_all(&bincode::serialize(&self)?)?;
+
+        Ok(())
+    }
+
+    /// Deserialise the fields of a request header, following the version major and minor.
+    ///
+    /// # Errors
+    /// - if the reserved fields are not zero or if unknown flags are set,
+    ///   `ResponseStatus::InvalidHeader` is returned.
+    /// - if the bytes cannot be unmarshalled into the contained fields,
+    ///   `ResponseStatus::InvalidEncoding` is returned.
+    pub(super) fn from_bytes(bytes: &[u8]) -> Result<WireHeader> {
+        let wire_header: WireHeader = bincode::deserialize(bytes)?;
+
+        if wire_header.reserved1 != 0x00 || wire_header.reserved2 != 0x00 {
+            return Err(ResponseStatus::InvalidHeader);
+        }
+        if wire_header.flags & !SUPPORTED_FLAGS != 0 {
+            error!("Unknown header flags set: {:#06x}", wire_header.flags);
+            return Err(ResponseStatus::InvalidHeader);
+        }
+
+        Ok(wire_header)
+    }
+}
+
+/// Conversion to a version 1.0 header, dropping the fields and flags introduced in 1.1.
+impl From<WireHeader> for WireHeader1_0 {
+    fn from(header: WireHeader) -> Self {
+        WireHeader1_0 {
+            flags: 0,
+            provider: header.provider,
+            session: header.session,
+            content_type: header.content_type,
+            accept_type: header.accept_type,
+            auth_type: header.auth_type,
+            body_len: header.body_len,
+            auth_len: header.auth_len,
+            opcode: header.opcode,
+            status: header.status,
+            reserved1: header.reserved1,
+            reserved2: header.reserved2,
+        }
+    }
+}
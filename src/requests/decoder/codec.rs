@@ -0,0 +1,182 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! Codecs to use the decoders with `tokio_util::codec` framed streams.
+use super::{Frame, FrameDecoder};
+use crate::requests::{ReadLimits, Request, Response, ResponseStatus, Result};
+use bytes::{Buf, BufMut, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+use zeroize::Zeroize;
+
+/// Decode the next frame from the buffer, removing the bytes consumed.
+fn decode_frame(frame_decoder: &mut FrameDecoder, src: &mut BytesMut) -> Result<Option<Frame>> {
+    let (consumed, frame) = frame_decoder.decode(src)?;
+    // The consumed bytes might contain authentication data. The copies made when the framed
+    // stream grows its buffer can not be zeroized from here though.
+    src[..consumed].zeroize();
+    src.advance(consumed);
+    Ok(frame)
+}
+
+/// Codec for the service end of a connection: decodes requests and encodes responses.
+#[derive(Clone, Debug)]
+pub struct ServiceCodec {
+    frame_decoder: FrameDecoder,
+}
+
+impl ServiceCodec {
+    /// Create a codec rejecting requests exceeding the given limits. A length in bytes can be
+    /// passed to only limit the body length, the authentication field then being limited by
+    /// `DEFAULT_AUTH_LEN_LIMIT`.
+    pub fn new(limits: impl Into<ReadLimits>) -> ServiceCodec {
+        ServiceCodec {
+            frame_decoder: FrameDecoder::new(limits.into(), true),
+        }
+    }
+}
+
+impl Decoder for ServiceCodec {
+    type Item = Request;
+    type Error = ResponseStatus;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Request>> {
+        decode_frame(&mut self.frame_decoder, src)?
+            .map(Frame::into_request)
+            .transpose()
+    }
+}
+
+impl Encoder<Response> for ServiceCodec {
+    type Error = ResponseStatus;
+
+    fn encode(&mut self, response: Response, dst: &mut BytesMut) -> Result<()> {
+        response.write_to_stream(&mut dst.writer())
+    }
+}
+
+/// Codec for the client end of a connection: decodes responses and encodes requests.
+#[derive(Clone, Debug)]
+pub struct ClientCodec {
+    frame_decoder: FrameDecoder,
+}
+
+impl ClientCodec {
+    /// Create a codec rejecting responses exceeding the given limits. A length in bytes can be
+    /// passed to only limit the body length.
+    pub fn new(limits: impl Into<ReadLimits>) -> ClientCodec {
+        ClientCodec {
+            frame_decoder: FrameDecoder::new(limits.into(), false),
+        }
+    }
+}
+
+impl Decoder for ClientCodec {
+    type Item = Response;
+    type Error = ResponseStatus;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Response>> {
+        decode_frame(&mut self.frame_decoder, src)?
+            .map(Frame::into_response)
+            .transpose()
+    }
+}
+
+impl Encoder<Request> for ClientCodec {
+    type Error = ResponseStatus;
+
+    fn encode(&mut self, request: Request, dst: &mut BytesMut) -> Result<()> {
+        request.write_to_stream(&mut dst.writer())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::requests::common::WireProtocolVersion;
+    use crate::requests::request::{RequestAuth, RequestBody, RequestHeader};
+    use crate::requests::response::ResponseBody;
+    use crate::requests::{AuthType, BodyType, Opcode, ProviderId};
+    use crate::secrecy::ExposeSecret;
+
+    fn get_request() -> Request {
+        Request {
+            header: RequestHeader {
+                provider: ProviderId::MbedCrypto,
+                session: 0,
+                content_type: BodyType::Protobuf,
+                accept_type: BodyType::Protobuf,
+                auth_type: AuthType::Direct,
+                opcode: Opcode::PsaGenerateKey,
+                wire_protocol_version: WireProtocolVersion::V1_1,
+                request_id: 7,
+                keep_alive: true,
+            },
+            body: RequestBody::from_bytes(vec![0x70, 0x80, 0x90]),
+            auth: RequestAuth::new(vec![0xa0, 0xb0]),
+        }
+    }
+
+    #[test]
+    fn request_through_codecs() {
+        let mut bytes = BytesMut::new();
+        ClientCodec::new(1000)
+            .encode(get_request(), &mut bytes)
+            .expect("Failed to encode request");
+
+        let mut service_codec = ServiceCodec::new(1000);
+        let mut src = BytesMut::from(&bytes[..10]);
+        assert!(service_codec
+            .decode(&mut src)
+            .expect("Failed to decode")
+            .is_none());
+        src.extend_from_slice(&bytes[10..]);
+
+        let request = service_codec
+            .decode(&mut src)
+            .expect("Failed to decode")
+            .expect("Request should be complete");
+        assert_eq!(request.header, get_request().header);
+        assert_eq!(request.body.bytes(), &[0x70, 0x80, 0x90]);
+        assert_eq!(request.auth.buffer.expose_secret(), &[0xa0, 0xb0]);
+        assert!(src.is_empty());
+    }
+
+    #[test]
+    fn response_through_codecs() {
+        let mut response = Response::from_request_header(
+            get_request().header,
+            ResponseStatus::PsaErrorInsufficientMemory,
+        );
+        response.body = ResponseBody::from_bytes(vec![0x11; 20]);
+
+        let mut bytes = BytesMut::new();
+        ServiceCodec::new(1000)
+            .encode(response, &mut bytes)
+            .expect("Failed to encode response");
+
+        let response = ClientCodec::new(1000)
+            .decode(&mut bytes)
+            .expect("Failed to decode")
+            .expect("Response should be complete");
+        assert_eq!(response.header.request_id, 7);
+        assert_eq!(
+            response.header.status,
+            ResponseStatus::PsaErrorInsufficientMemory
+        );
+        assert_eq!(response.body.len(), 20);
+
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn body_too_large() {
+        let mut bytes = BytesMut::new();
+        ClientCodec::new(1000)
+            .encode(get_request(), &mut bytes)
+            .expect("Failed to encode request");
+
+        assert_eq!(
+            ServiceCodec::new(1).decode(&mut bytes).unwrap_err(),
+            ResponseStatus::BodySizeExceedsLimit
+        );
+    }
+}
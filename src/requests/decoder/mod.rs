@@ -0,0 +1,475 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # Incremental decoders
+//!
+//! `Request::read_from_stream` and `Response::read_from_stream` block until a whole frame has
+//! been read and lose the bytes already read if the stream runs dry. The decoders in this module
+//! are instead fed with chunks of bytes as they are received, for example from a non-blocking
+//! socket, and yield requests or responses once their whole frame has been received.
+use super::common::WireHeader as Raw;
+use super::request::{RequestAuth, RequestBody};
+use super::response::ResponseBody;
+use super::{ReadLimits, Request, Response, Result};
+use derivative::Derivative;
+use std::cmp;
+use std::convert::TryInto;
+use std::mem;
+use zeroize::{Zeroize, Zeroizing};
+
+#[cfg(feature = "codec")]
+mod codec;
+
+#[cfg(feature = "codec")]
+pub use codec::{ClientCodec, ServiceCodec};
+
+#[derive(Copy, Clone, Debug)]
+enum State {
+    /// Waiting for a complete header.
+    Header,
+    /// Header decoded, waiting for the body and authentication field.
+    Payload {
+        header: Raw,
+        body_len: usize,
+        auth_len: usize,
+    },
+}
+
+/// Complete frame, before its header is converted to the native format.
+///
+/// The body and authentication bytes are zeroized if the frame is dropped, for example when its
+/// header can not be converted.
+struct Frame {
+    header: Raw,
+    body: Zeroizing<Vec<u8>>,
+    auth: Zeroizing<Vec<u8>>,
+}
+
+impl Frame {
+    fn into_request(mut self) -> Result<Request> {
+        Ok(Request {
+            header: self.header.try_into()?,
+            body: RequestBody::from_bytes(mem::take(&mut *self.body)),
+            auth: RequestAuth::new(mem::take(&mut *self.auth)),
+        })
+    }
+
+    fn into_response(mut self) -> Result<Response> {
+        Ok(Response {
+            header: self.header.try_into()?,
+            body: ResponseBody::from_bytes(mem::take(&mut *self.body)),
+        })
+    }
+}
+
+/// State machine splitting a sequence of bytes into frames.
+///
+/// It is not `Copy` as a copy made in the middle of a frame would desynchronise from the stream.
+#[derive(Clone, Debug)]
+struct FrameDecoder {
+    state: State,
+    limits: ReadLimits,
+    /// Whether frames contain an authentication field, as requests do.
+    with_auth: bool,
+}
+
+impl FrameDecoder {
+    fn new(limits: ReadLimits, with_auth: bool) -> FrameDecoder {
+        FrameDecoder {
+            state: State::Header,
+            limits,
+            with_auth,
+        }
+    }
+
+    /// Decode the next frame from the start of `bytes`.
+    ///
+    /// Returns the number of bytes consumed, which must not be passed again, and the frame if it
+    /// is complete.
+    fn decode(&mut self, bytes: &[u8]) -> Result<(usize, Option<Frame>)> {
+        let mut consumed = 0;
+
+        if let State::Header = self.state {
+            // Invalid headers are rejected without waiting for their whole claimed size.
+            match Raw::check_prefix(bytes)? {
+                Some(hdr_len) if bytes.len() >= hdr_len => (),
+                _ => return Ok((0, None)),
+            }
+
+            let (header, hdr_len) = Raw::parse(bytes)?;
+            consumed = hdr_len;
+            let (body_len, auth_len) = header.checked_payload_len(&self.limits, self.with_auth)?;
+            self.state = State::Payload {
+                header,
+                body_len,
+                auth_len,
+            };
+        }
+
+        if let State::Payload {
+            header,
+            body_len,
+            auth_len,
+        } = self.state
+        {
+            let payload = &bytes[consumed..];
+            if payload.len() < body_len + auth_len {
+                return Ok((consumed, None));
+            }
+
+            self.state = State::Header;
+            let frame = Frame {
+                header,
+                body: Zeroizing::new(payload[..body_len].to_vec()),
+                auth: Zeroizing::new(payload[body_len..body_len + auth_len].to_vec()),
+            };
+            return Ok((consumed + body_len + auth_len, Some(frame)));
+        }
+
+        Ok((consumed, None))
+    }
+}
+
+/// Bytes received and waiting to be decoded.
+///
+/// As they might contain authentication data, the bytes are zeroized once consumed and no copy of
+/// them is left behind when the buffer is compacted or grown.
+#[derive(Derivative, Default)]
+#[derivative(Debug)]
+struct Buffer {
+    #[derivative(Debug = "ignore")]
+    bytes: Zeroizing<Vec<u8>>,
+    /// Offset of the first byte not consumed yet.
+    start: usize,
+}
+
+impl Buffer {
+    fn unconsumed(&self) -> &[u8] {
+        &self.bytes[self.start..]
+    }
+
+    fn push(&mut self, bytes: &[u8]) {
+        let remaining = self.bytes.len() - self.start;
+        if self.bytes.len() + bytes.len() > self.bytes.capacity() {
+            // Only move the remaining bytes within the buffer if there are fewer of them than of
+            // consumed ones, to keep the cost of the moves linear.
+            if remaining <= self.start && remaining + bytes.len() <= self.bytes.capacity() {
+                self.bytes.copy_within(self.start.., 0);
+                self.bytes[remaining..].zeroize();
+                self.bytes.truncate(remaining);
+            } else {
+                // The spare capacity of the current buffer only contains zeros and its bytes are
+                // zeroized when it is dropped.
+                let capacity = cmp::max(2 * self.bytes.capacity(), remaining + bytes.len());
+                let mut grown = Vec::with_capacity(capacity);
+                grown.extend_from_slice(self.unconsumed());
+                self.bytes = Zeroizing::new(grown);
+            }
+            self.start = 0;
+        }
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    fn consume(&mut self, len: usize) {
+        self.bytes[self.start..self.start + len].zeroize();
+        self.start += len;
+        if self.start == self.bytes.len() {
+            // All the bytes have been zeroized.
+            self.bytes.clear();
+            self.start = 0;
+        }
+    }
+}
+
+/// Decoder of requests from chunks of bytes.
+///
+/// Once an error is returned, the framing of the connection is lost and it should be closed.
+#[derive(Debug)]
+pub struct RequestDecoder {
+    frame_decoder: FrameDecoder,
+    buffer: Buffer,
+}
+
+impl RequestDecoder {
+    /// Create a decoder rejecting requests exceeding the given limits. A length in bytes can be
+    /// passed to only limit the body length, the authentication field then being limited by
+    /// `DEFAULT_AUTH_LEN_LIMIT`.
+    pub fn new(limits: impl Into<ReadLimits>) -> RequestDecoder {
+        RequestDecoder {
+            frame_decoder: FrameDecoder::new(limits.into(), true),
+            buffer: Buffer::default(),
+        }
+    }
+
+    /// Add bytes received from the connection to the ones waiting to be decoded.
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.buffer.push(bytes);
+    }
+
+    /// Decode the next request from the bytes pushed so far.
+    ///
+    /// Returns `None` if more bytes are needed to complete the request. Several requests can be
+    /// decoded from the bytes pushed at once by calling this method until it returns `None`.
+    ///
+    /// # Errors
+    /// The same errors as `Request::read_from_stream` are returned, except for the ones
+    /// related to the reads themselves.
+    pub fn decode(&mut self) -> Result<Option<Request>> {
+        let (consumed, frame) = self.frame_decoder.decode(self.buffer.unconsumed())?;
+        self.buffer.consume(consumed);
+        frame.map(Frame::into_request).transpose()
+    }
+}
+
+/// Decoder of responses from chunks of bytes.
+///
+/// Once an error is returned, the framing of the connection is lost and it should be closed.
+#[derive(Debug)]
+pub struct ResponseDecoder {
+    frame_decoder: FrameDecoder,
+    buffer: Buffer,
+}
+
+impl ResponseDecoder {
+    /// Create a decoder rejecting responses exceeding the given limits. A length in bytes can be
+    /// passed to only limit the body length.
+    pub fn new(limits: impl Into<ReadLimits>) -> ResponseDecoder {
+        ResponseDecoder {
+            frame_decoder: FrameDecoder::new(limits.into(), false),
+            buffer: Buffer::default(),
+        }
+    }
+
+    /// Add bytes received from the connection to the ones waiting to be decoded.
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.buffer.push(bytes);
+    }
+
+    /// Decode the next response from the bytes pushed so far.
+    ///
+    /// Returns `None` if more bytes are needed to complete the response. Several responses can
+    /// be decoded from the bytes pushed at once by calling this method until it returns `None`.
+    ///
+    /// # Errors
+    /// The same errors as `Response::read_from_stream` are returned, except for the ones
+    /// related to the reads themselves.
+    pub fn decode(&mut self) -> Result<Option<Response>> {
+        let (consumed, frame) = self.frame_decoder.decode(self.buffer.unconsumed())?;
+        self.buffer.consume(consumed);
+        frame.map(Frame::into_response).transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::common::WireProtocolVersion;
+    use super::super::request::RequestHeader;
+    use super::super::{AuthType, BodyType, Opcode, ProviderId, ResponseStatus};
+    use super::*;
+    use crate::secrecy::ExposeSecret;
+
+    fn get_request_bytes() -> Vec<u8> {
+        let request = Request {
+            header: RequestHeader {
+                provider: ProviderId::Core,
+                session: 0,
+                content_type: BodyType::Protobuf,
+                accept_type: BodyType::Protobuf,
+                auth_type: AuthType::Direct,
+                opcode: Opcode::ListOpcodes,
+                wire_protocol_version: WireProtocolVersion::V1_1,
+                request_id: 1,
+                keep_alive: true,
+            },
+            body: RequestBody::from_bytes(vec![0x70, 0x80, 0x90]),
+            auth: RequestAuth::new(vec![0xa0, 0xb0]),
+        };
+
+        let mut bytes = Vec::new();
+        request
+            .write_to_stream(&mut bytes)
+            .expect("Failed to write request");
+        bytes
+    }
+
+    fn get_response_bytes() -> Vec<u8> {
+        let mut response = Response::from_status(ResponseStatus::PsaErrorDoesNotExist);
+        response.header.opcode = Opcode::ListOpcodes;
+        response.body = ResponseBody::from_bytes(vec![0x70, 0x80, 0x90]);
+
+        let mut bytes = Vec::new();
+        response
+            .write_to_stream(&mut bytes)
+            .expect("Failed to write response");
+        bytes
+    }
+
+    #[test]
+    fn request_byte_by_byte() {
+        let bytes = get_request_bytes();
+        let mut decoder = RequestDecoder::new(1000);
+
+        for byte in &bytes[..bytes.len() - 1] {
+            decoder.push(&[*byte]);
+            assert!(decoder.decode().expect("Failed to decode").is_none());
+        }
+        decoder.push(&bytes[bytes.len() - 1..]);
+
+        let request = decoder
+            .decode()
+            .expect("Failed to decode")
+            .expect("Request should be complete");
+        assert_eq!(request.header.opcode, Opcode::ListOpcodes);
+        assert_eq!(request.header.request_id, 1);
+        assert_eq!(request.body.bytes(), &[0x70, 0x80, 0x90]);
+        assert_eq!(request.auth.buffer.expose_secret(), &[0xa0, 0xb0]);
+        assert!(decoder.decode().expect("Failed to decode").is_none());
+        assert!(decoder.buffer.unconsumed().is_empty());
+    }
+
+    #[test]
+    fn several_requests_in_one_chunk() {
+        let bytes = get_request_bytes();
+        let mut chunk = bytes.clone();
+        chunk.extend_from_slice(&bytes);
+        chunk.extend_from_slice(&bytes[..10]);
+        let mut decoder = RequestDecoder::new(1000);
+        decoder.push(&chunk);
+
+        assert!(decoder.decode().expect("Failed to decode").is_some());
+        assert!(decoder.decode().expect("Failed to decode").is_some());
+        assert!(decoder.decode().expect("Failed to decode").is_none());
+
+        decoder.push(&bytes[10..]);
+        assert!(decoder.decode().expect("Failed to decode").is_some());
+    }
+
+    #[test]
+    fn consumed_bytes_zeroized() {
+        let bytes = get_request_bytes();
+        let mut decoder = RequestDecoder::new(1000);
+        decoder.push(&bytes);
+        decoder.push(&bytes[..10]);
+
+        assert!(decoder.decode().expect("Failed to decode").is_some());
+        let buffer = &decoder.buffer;
+        assert!(buffer.bytes[..buffer.start].iter().all(|byte| *byte == 0));
+        assert_eq!(buffer.unconsumed(), &bytes[..10]);
+
+        decoder.push(&bytes[10..]);
+        assert_eq!(decoder.buffer.unconsumed(), bytes.as_slice());
+        assert!(decoder.decode().expect("Failed to decode").is_some());
+        assert!(decoder.buffer.bytes.is_empty());
+    }
+
+    #[test]
+    fn buffer_compaction() {
+        let mut buffer = Buffer::default();
+        buffer.push(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        let capacity = buffer.bytes.capacity();
+        buffer.consume(6);
+        buffer.push(&[9, 10]);
+        assert_eq!(buffer.unconsumed(), &[7, 8, 9, 10]);
+        assert_eq!(buffer.bytes.capacity(), capacity);
+        buffer.consume(4);
+        assert!(buffer.unconsumed().is_empty());
+        assert_eq!(buffer.start, 0);
+    }
+
+    #[test]
+    fn request_body_too_large() {
+        let bytes = get_request_bytes();
+        let mut decoder = RequestDecoder::new(2);
+        // The body length is checked as soon as the header is complete.
+        decoder.push(&bytes[..44]);
+
+        assert_eq!(
+            decoder.decode().unwrap_err(),
+            ResponseStatus::BodySizeExceedsLimit
+        );
+    }
+
+    #[test]
+    fn request_auth_too_large() {
+        let bytes = get_request_bytes();
+        let mut decoder = RequestDecoder::new(ReadLimits {
+            body_len: 1000,
+            auth_len: 1,
+            frame_len: 1000,
+        });
+        decoder.push(&bytes);
+
+        assert_eq!(
+            decoder.decode().unwrap_err(),
+            ResponseStatus::AuthSizeExceedsLimit
+        );
+    }
+
+    #[test]
+    fn invalid_header() {
+        let mut bytes = get_request_bytes();
+        bytes[0] = 0;
+        let mut decoder = RequestDecoder::new(1000);
+        decoder.push(&bytes);
+
+        assert_eq!(decoder.decode().unwrap_err(), ResponseStatus::InvalidHeader);
+    }
+
+    #[test]
+    fn unknown_opcode() {
+        let mut bytes = get_request_bytes();
+        // Opcode field of the 1.1 header.
+        bytes[28..32].copy_from_slice(&0xffff_u32.to_le_bytes());
+        let mut decoder = RequestDecoder::new(1000);
+        decoder.push(&bytes);
+
+        assert_eq!(
+            decoder.decode().unwrap_err(),
+            ResponseStatus::OpcodeDoesNotExist
+        );
+        // The whole frame was consumed, and its buffered bytes zeroized.
+        assert!(decoder.buffer.bytes.is_empty());
+    }
+
+    #[test]
+    fn invalid_prefix() {
+        let bytes = get_request_bytes();
+
+        let mut decoder = RequestDecoder::new(1000);
+        decoder.push(&[0, 0, 0, 0]);
+        assert_eq!(decoder.decode().unwrap_err(), ResponseStatus::InvalidHeader);
+
+        // Header size much bigger than the one of version 1.1
+        let mut decoder = RequestDecoder::new(1000);
+        decoder.push(&bytes[..4]);
+        decoder.push(&[0xff, 0xff]);
+        assert!(decoder.decode().expect("Failed to decode").is_none());
+        decoder.push(&bytes[6..8]);
+        assert_eq!(decoder.decode().unwrap_err(), ResponseStatus::InvalidHeader);
+
+        let mut decoder = RequestDecoder::new(1000);
+        decoder.push(&bytes[..6]);
+        decoder.push(&[2, 0]);
+        assert_eq!(
+            decoder.decode().unwrap_err(),
+            ResponseStatus::WireProtocolVersionNotSupported
+        );
+    }
+
+    #[test]
+    fn response_in_chunks() {
+        let bytes = get_response_bytes();
+        let mut decoder = ResponseDecoder::new(1000);
+
+        decoder.push(&bytes[..20]);
+        assert!(decoder.decode().expect("Failed to decode").is_none());
+        decoder.push(&bytes[20..]);
+
+        let response = decoder
+            .decode()
+            .expect("Failed to decode")
+            .expect("Response should be complete");
+        assert_eq!(response.header.opcode, Opcode::ListOpcodes);
+        assert_eq!(response.header.status, ResponseStatus::PsaErrorDoesNotExist);
+        assert_eq!(response.body.bytes(), &[0x70, 0x80, 0x90]);
+    }
+}
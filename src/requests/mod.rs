@@ -6,14 +6,17 @@
 //! service returns.
 use num_derive::FromPrimitive;
 
+mod read_limits;
 mod response_status;
 
 pub mod utils;
 pub mod common;
+pub mod decoder;
 pub mod request;
 pub mod response;
 #[cfg(feature = "fuzz")]
 use arbitrary::Arbitrary;
+pub use read_limits::{ReadLimits, DEFAULT_AUTH_LEN_LIMIT};
 pub use request::Request;
 pub use response::Response;
 pub use response_status::{ResponseStatus, Result};
@@ -123,6 +126,14 @@ pub enum Opcode {
     PsaAeadDecrypt = 0x0012,
     /// PsaRawKeyAgreement operation
     PsaRawKeyAgreement = 0x0013,
+    /// PsaCipherEncrypt operation
+    PsaCipherEncrypt = 0x0014,
+    /// PsaCipherDecrypt operation
+    PsaCipherDecrypt = 0x0015,
+    /// PsaMacCompute operation
+    PsaMacCompute = 0x0016,
+    /// PsaMacVerify operation
+    PsaMacVerify = 0x0017,
     /// PsaSignMessage operation
     PsaSignMessage = 0x0018,
     /// PsaVerifyMessage operation
@@ -133,6 +144,56 @@ pub enum Opcode {
     ListClients = 0x001B,
     /// DeleteClient operation (admin operation)
     DeleteClient = 0x001C,
+    /// PsaKeyDerivation operation
+    PsaKeyDerivation = 0x001D,
+    /// PsaKeyAgreement operation
+    PsaKeyAgreement = 0x001E,
+    /// CanDoCrypto operation
+    CanDoCrypto = 0x001F,
+    /// PrepareKeyAttestation operation
+    PrepareKeyAttestation = 0x0020,
+    /// AttestKey operation
+    AttestKey = 0x0021,
+    /// PsaHashSetup operation
+    PsaHashSetup = 0x0022,
+    /// PsaHashUpdate operation
+    PsaHashUpdate = 0x0023,
+    /// PsaHashFinish operation
+    PsaHashFinish = 0x0024,
+    /// PsaHashAbort operation
+    PsaHashAbort = 0x0025,
+    /// PsaAeadEncryptSetup operation
+    PsaAeadEncryptSetup = 0x0026,
+    /// PsaAeadDecryptSetup operation
+    PsaAeadDecryptSetup = 0x0027,
+    /// PsaAeadUpdateAd operation
+    PsaAeadUpdateAd = 0x0028,
+    /// PsaAeadUpdate operation
+    PsaAeadUpdate = 0x0029,
+    /// PsaAeadFinish operation
+    PsaAeadFinish = 0x002A,
+    /// PsaAeadVerify operation
+    PsaAeadVerify = 0x002B,
+    /// PsaAeadAbort operation
+    PsaAeadAbort = 0x002C,
+    /// PsaCopyKey operation
+    PsaCopyKey = 0x002D,
+    /// PsaGetKeyAttributes operation
+    PsaGetKeyAttributes = 0x002E,
+    /// Batch operation
+    Batch = 0x002F,
+    /// AttachCertificate operation
+    AttachCertificate = 0x0030,
+    /// GetCertificate operation
+    GetCertificate = 0x0031,
+    /// ListCertificates operation
+    ListCertificates = 0x0032,
+    /// RemoveCertificate operation
+    RemoveCertificate = 0x0033,
+    /// GenerateCsr operation
+    GenerateCsr = 0x0034,
+    /// RotateKey operation
+    RotateKey = 0x0035,
 }
 
 impl Opcode {
@@ -146,7 +207,8 @@ impl Opcode {
             | Opcode::ListAuthenticators
             | Opcode::ListKeys
             | Opcode::ListClients
-            | Opcode::DeleteClient => true,
+            | Opcode::DeleteClient
+            | Opcode::CanDoCrypto => true,
             Opcode::PsaGenerateKey
             | Opcode::PsaDestroyKey
             | Opcode::PsaSignHash
@@ -163,7 +225,35 @@ impl Opcode {
             | Opcode::PsaHashCompare
             | Opcode::PsaAeadEncrypt
             | Opcode::PsaAeadDecrypt
-            | Opcode::PsaRawKeyAgreement => false,
+            | Opcode::PsaRawKeyAgreement
+            | Opcode::PsaCipherEncrypt
+            | Opcode::PsaCipherDecrypt
+            | Opcode::PsaMacCompute
+            | Opcode::PsaMacVerify
+            | Opcode::PsaKeyDerivation
+            | Opcode::PsaKeyAgreement
+            | Opcode::PrepareKeyAttestation
+            | Opcode::AttestKey
+            | Opcode::PsaHashSetup
+            | Opcode::PsaHashUpdate
+            | Opcode::PsaHashFinish
+            | Opcode::PsaHashAbort
+            | Opcode::PsaAeadEncryptSetup
+            | Opcode::PsaAeadDecryptSetup
+            | Opcode::PsaAeadUpdateAd
+            | Opcode::PsaAeadUpdate
+            | Opcode::PsaAeadFinish
+            | Opcode::PsaAeadVerify
+            | Opcode::PsaAeadAbort
+            | Opcode::PsaCopyKey
+            | Opcode::PsaGetKeyAttributes
+            | Opcode::Batch
+            | Opcode::AttachCertificate
+            | Opcode::GetCertificate
+            | Opcode::ListCertificates
+            | Opcode::RemoveCertificate
+            | Opcode::GenerateCsr
+            | Opcode::RotateKey => false,
         }
     }
 
@@ -193,7 +283,36 @@ impl Opcode {
             | Opcode::PsaHashCompare
             | Opcode::PsaAeadEncrypt
             | Opcode::PsaAeadDecrypt
-            | Opcode::PsaRawKeyAgreement => false,
+            | Opcode::PsaRawKeyAgreement
+            | Opcode::PsaCipherEncrypt
+            | Opcode::PsaCipherDecrypt
+            | Opcode::PsaMacCompute
+            | Opcode::PsaMacVerify
+            | Opcode::PsaKeyDerivation
+            | Opcode::PsaKeyAgreement
+            | Opcode::CanDoCrypto
+            | Opcode::PrepareKeyAttestation
+            | Opcode::AttestKey
+            | Opcode::PsaHashSetup
+            | Opcode::PsaHashUpdate
+            | Opcode::PsaHashFinish
+            | Opcode::PsaHashAbort
+            | Opcode::PsaAeadEncryptSetup
+            | Opcode::PsaAeadDecryptSetup
+            | Opcode::PsaAeadUpdateAd
+            | Opcode::PsaAeadUpdate
+            | Opcode::PsaAeadFinish
+            | Opcode::PsaAeadVerify
+            | Opcode::PsaAeadAbort
+            | Opcode::PsaCopyKey
+            | Opcode::PsaGetKeyAttributes
+            | Opcode::Batch
+            | Opcode::AttachCertificate
+            | Opcode::GetCertificate
+            | Opcode::ListCertificates
+            | Opcode::RemoveCertificate
+            | Opcode::GenerateCsr
+            | Opcode::RotateKey => false,
         }
     }
 
@@ -240,6 +359,7 @@ impl fmt::Display for AuthType {
 fn check_opcode_nature() {
     assert!(Opcode::ListKeys.is_core());
     assert!(!Opcode::ListKeys.is_crypto());
+    assert!(Opcode::CanDoCrypto.is_core());
     assert!(Opcode::PsaGenerateKey.is_crypto());
     assert!(Opcode::ListClients.is_admin());
     assert!(!Opcode::PsaGenerateKey.is_admin());
@@ -0,0 +1,35 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+/// Default limit on the length of the authentication field of requests, in bytes, used when only
+/// a body length limit is given. It leaves room for the largest tokens used by the supported
+/// authentication methods, such as JWT-SVIDs.
+pub const DEFAULT_AUTH_LEN_LIMIT: usize = 8 * 1024;
+
+/// Limits on the sizes of the frames read, so that peers cannot force large allocations.
+///
+/// The limits are checked against the lengths specified in the header, before the body and
+/// authentication field are read. All limits are in bytes.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ReadLimits {
+    /// Maximum length of the body. Exceeding it returns `BodySizeExceedsLimit`.
+    pub body_len: usize,
+    /// Maximum length of the authentication field of requests. Exceeding it returns
+    /// `AuthSizeExceedsLimit`.
+    pub auth_len: usize,
+    /// Maximum length of the whole frame: header, body and authentication field. Exceeding it
+    /// returns `FrameSizeExceedsLimit`.
+    pub frame_len: usize,
+}
+
+/// Limits bounding the length of the body, the authentication field being bounded by
+/// `DEFAULT_AUTH_LEN_LIMIT`.
+impl From<usize> for ReadLimits {
+    fn from(body_len: usize) -> Self {
+        ReadLimits {
+            body_len,
+            auth_len: DEFAULT_AUTH_LEN_LIMIT,
+            frame_len: usize::MAX,
+        }
+    }
+}
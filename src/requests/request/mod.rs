@@ -3,9 +3,9 @@
 //! # Request definition
 //!
 //! A `Request` is to the service to execute one operation.
-use super::common::wire_header_1_0::WireHeader as Raw;
+use super::common::WireHeader as Raw;
 use super::response::ResponseHeader;
-use crate::requests::{ResponseStatus, Result};
+use crate::requests::{ReadLimits, ResponseStatus, Result};
 use crate::secrecy::ExposeSecret;
 #[cfg(feature = "fuzz")]
 use arbitrary::Arbitrary;
@@ -13,6 +13,8 @@ use derivative::Derivative;
 use log::error;
 use std::convert::{TryFrom, TryInto};
 use std::io::{Read, Write};
+#[cfg(feature = "async")]
+use tokio::io::{AsyncRead, AsyncWrite};
 
 mod request_auth;
 mod request_body;
@@ -42,6 +44,41 @@ pub struct Request {
     pub auth: RequestAuth,
 }
 
+/// Borrowed view of a request frame held in a contiguous buffer.
+///
+/// The body and authentication fields reference the buffer the request was parsed from.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct RequestView<'a> {
+    /// Request header
+    pub header: RequestHeader,
+    /// Bytes of the request body.
+    pub body: &'a [u8],
+    /// Bytes of the authentication field.
+    #[derivative(Debug = "ignore")]
+    pub auth: &'a [u8],
+    frame_len: usize,
+}
+
+impl RequestView<'_> {
+    /// Number of bytes of the buffer spanned by the request frame. Any following bytes belong
+    /// to the next frame.
+    pub fn frame_len(&self) -> usize {
+        self.frame_len
+    }
+}
+
+/// Copy the fields of the view to get an owned request.
+impl From<RequestView<'_>> for Request {
+    fn from(view: RequestView<'_>) -> Self {
+        Request {
+            header: view.header,
+            body: RequestBody::from_bytes(view.body.to_vec()),
+            auth: RequestAuth::new(view.auth.to_vec()),
+        }
+    }
+}
+
 impl Request {
     /// Create a request with "default" header and empty body.
     /// Available for testing purposes only.
@@ -56,17 +93,18 @@ impl Request {
 
     /// Serialise request and write it to given stream.
     ///
-    /// Request header is first converted to its raw format before serialization.
+    /// Request header is first converted to its raw format before serialization, in the wire
+    /// protocol version chosen in the header.
     ///
     /// # Errors
     /// - if an IO operation fails while writing any of the subfields of the request,
-    /// `ResponseStatus::ConnectionError` is returned.
+    ///   `ResponseStatus::ConnectionError` is returned.
     /// - if encoding any of the fields in the header fails, `ResponseStatus::InvalidEncoding`
-    /// is returned.
+    ///   is returned.
+    /// - if the wire protocol version chosen in the header is not supported,
+    ///   `ResponseStatus::WireProtocolVersionNotSupported` is returned.
     pub fn write_to_stream(self, stream: &mut impl Write) -> Result<()> {
-        let mut raw_header: Raw = self.header.into();
-        raw_header.body_len = u32::try_from(self.body.len())?;
-        raw_header.auth_len = u16::try_from(self.auth.buffer.expose_secret().len())?;
+        let raw_header = self.raw_header()?;
         raw_header.write_to_stream(stream)?;
 
         self.body.write_to_stream(stream)?;
@@ -77,27 +115,105 @@ impl Request {
 
     /// Deserialise request from given stream.
     ///
-    /// Request header is parsed from its raw form, ensuring that all fields are valid.
-    /// The `body_len_limit` parameter allows the interface client to reject requests that are
-    /// longer than a predefined limit. The length limit is in bytes.
+    /// Request header is parsed from its raw form, ensuring that all fields are valid. All the
+    /// supported versions of the wire protocol are accepted.
+    /// The `limits` parameter allows the interface client to reject requests that are
+    /// longer than predefined limits, before allocating memory for them. A length in bytes can
+    /// be passed to only limit the body length, the authentication field then being limited by
+    /// `DEFAULT_AUTH_LEN_LIMIT`.
     ///
     /// # Errors
     /// - if reading any of the subfields (header, body or auth) fails, the corresponding
-    /// `ResponseStatus` will be returned.
+    ///   `ResponseStatus` will be returned.
     /// - if the request body size specified in the header is larger than the limit passed as
-    /// a parameter, `BodySizeExceedsLimit` will be returned.
-    pub fn read_from_stream(stream: &mut impl Read, body_len_limit: usize) -> Result<Request> {
+    ///   a parameter, `BodySizeExceedsLimit` will be returned.
+    /// - if the request auth size specified in the header is larger than the limit passed as
+    ///   a parameter, `AuthSizeExceedsLimit` will be returned.
+    /// - if the request frame size is larger than the limit passed as a parameter,
+    ///   `FrameSizeExceedsLimit` will be returned.
+    pub fn read_from_stream(
+        stream: &mut impl Read,
+        limits: impl Into<ReadLimits>,
+    ) -> Result<Request> {
         let raw_header = Raw::read_from_stream(stream)?;
-        let body_len = usize::try_from(raw_header.body_len)?;
-        if body_len > body_len_limit {
+        let (body_len, auth_len) = raw_header.checked_payload_len(&limits.into(), true)?;
+        let body = RequestBody::read_from_stream(stream, body_len)?;
+        let auth = RequestAuth::read_from_stream(stream, auth_len)?;
+
+        Ok(Request {
+            header: raw_header.try_into()?,
+            body,
+            auth,
+        })
+    }
+
+    /// Parse a request from the start of a contiguous buffer, without copying the body and
+    /// authentication fields.
+    ///
+    /// The buffer can contain bytes following the request frame, whose length is given by
+    /// `RequestView::frame_len`. As the whole frame is already in memory, no limit is put on
+    /// the body length: it is up to the caller to check the length of the body.
+    ///
+    /// # Errors
+    /// - if the buffer is too short to contain the request frame,
+    ///   `ResponseStatus::InvalidEncoding` is returned.
+    /// - otherwise, the same errors as `read_from_stream` are returned.
+    pub fn parse(bytes: &[u8]) -> Result<RequestView<'_>> {
+        let (raw_header, hdr_len) = Raw::parse(bytes)?;
+        let body_len = usize::try_from(raw_header.body_len())?;
+        let auth_len = usize::from(raw_header.auth_len());
+        let payload_len = body_len.checked_add(auth_len).ok_or_else(|| {
+            error!("Request payload length overflows");
+            ResponseStatus::InvalidEncoding
+        })?;
+        let payload = &bytes[hdr_len..];
+        if payload.len() < payload_len {
             error!(
-                "Request body length ({}) bigger than the limit given ({}).",
-                body_len, body_len_limit
+                "Buffer of {} bytes too short for a request payload of {} bytes",
+                payload.len(),
+                payload_len
             );
-            return Err(ResponseStatus::BodySizeExceedsLimit);
+            return Err(ResponseStatus::InvalidEncoding);
         }
-        let body = RequestBody::read_from_stream(stream, body_len)?;
-        let auth = RequestAuth::read_from_stream(stream, usize::try_from(raw_header.auth_len)?)?;
+
+        Ok(RequestView {
+            header: raw_header.try_into()?,
+            body: &payload[..body_len],
+            auth: &payload[body_len..payload_len],
+            frame_len: hdr_len + payload_len,
+        })
+    }
+
+    /// Serialise request and write it to given asynchronous stream.
+    ///
+    /// # Errors
+    /// The same errors as `write_to_stream` are returned.
+    #[cfg(feature = "async")]
+    pub async fn write_to_async_stream(self, stream: &mut (impl AsyncWrite + Unpin)) -> Result<()> {
+        let raw_header = self.raw_header()?;
+        raw_header.write_to_async_stream(stream).await?;
+
+        self.body.write_to_async_stream(stream).await?;
+        self.auth.write_to_async_stream(stream).await?;
+
+        Ok(())
+    }
+
+    /// Deserialise request from given asynchronous stream.
+    ///
+    /// The `limits` parameter is used as in `read_from_stream`.
+    ///
+    /// # Errors
+    /// The same errors as `read_from_stream` are returned.
+    #[cfg(feature = "async")]
+    pub async fn read_from_async_stream(
+        stream: &mut (impl AsyncRead + Unpin),
+        limits: impl Into<ReadLimits>,
+    ) -> Result<Request> {
+        let raw_header = Raw::read_from_async_stream(stream).await?;
+        let (body_len, auth_len) = raw_header.checked_payload_len(&limits.into(), true)?;
+        let body = RequestBody::read_from_async_stream(stream, body_len).await?;
+        let auth = RequestAuth::read_from_async_stream(stream, auth_len).await?;
 
         Ok(Request {
             header: raw_header.try_into()?,
@@ -105,6 +221,14 @@ impl Request {
             auth,
         })
     }
+
+    /// Convert the header to its raw format, filling in the body and auth lengths.
+    fn raw_header(&self) -> Result<Raw> {
+        let mut raw_header = Raw::try_from(self.header)?;
+        raw_header.set_body_len(u32::try_from(self.body.len())?);
+        raw_header.set_auth_len(u16::try_from(self.auth.buffer.expose_secret().len())?);
+        Ok(raw_header)
+    }
 }
 
 #[cfg(feature = "testing")]
@@ -116,6 +240,8 @@ impl Default for Request {
 
 /// Conversion from `RequestHeader` to `ResponseHeader` is useful for
 /// when reversing data flow, from handling a request to handling a response.
+///
+/// The response uses the same wire protocol version as the request.
 impl From<RequestHeader> for ResponseHeader {
     fn from(req_hdr: RequestHeader) -> ResponseHeader {
         ResponseHeader {
@@ -124,12 +250,16 @@ impl From<RequestHeader> for ResponseHeader {
             content_type: req_hdr.accept_type,
             opcode: req_hdr.opcode,
             status: ResponseStatus::Success,
+            wire_protocol_version: req_hdr.wire_protocol_version,
+            request_id: req_hdr.request_id,
+            keep_alive: req_hdr.keep_alive,
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::super::common::WireProtocolVersion;
     use super::super::utils::tests as test_utils;
     use super::super::{AuthType, BodyType, Opcode, ProviderId, ResponseStatus};
     use super::*;
@@ -262,6 +392,189 @@ mod tests {
         );
     }
 
+    #[test]
+    fn request_1_1_round_trip() {
+        let mut mock = test_utils::MockReadWrite { buffer: Vec::new() };
+        let mut request = get_request();
+        request.header.wire_protocol_version = WireProtocolVersion::V1_1;
+        request.header.request_id = 0x42;
+        request.header.keep_alive = true;
+        let header = request.header;
+
+        request
+            .write_to_stream(&mut mock)
+            .expect("Failed to write request");
+        assert_eq!(mock.buffer.len(), get_request_bytes().len() + 8);
+
+        let request = Request::read_from_stream(&mut mock, 1000).expect("Failed to read request");
+        assert_eq!(request.header, header);
+        assert_eq!(request.body, get_request().body);
+
+        let resp_hdr: ResponseHeader = request.header.into();
+        assert_eq!(resp_hdr.wire_protocol_version, WireProtocolVersion::V1_1);
+        assert_eq!(resp_hdr.request_id, 0x42);
+        assert!(resp_hdr.keep_alive);
+    }
+
+    #[test]
+    fn unsupported_version_to_stream() {
+        let mut mock = test_utils::MockReadWrite { buffer: Vec::new() };
+        let mut request = get_request();
+        request.header.wire_protocol_version = WireProtocolVersion { major: 2, minor: 0 };
+
+        assert_eq!(
+            request.write_to_stream(&mut mock).unwrap_err(),
+            ResponseStatus::WireProtocolVersionNotSupported
+        );
+    }
+
+    #[test]
+    fn request_1_1_fields_in_1_0_header() {
+        let mut mock = test_utils::MockReadWrite { buffer: Vec::new() };
+        let mut request = get_request();
+        request.header.request_id = 0x42;
+        assert_eq!(
+            request.write_to_stream(&mut mock).unwrap_err(),
+            ResponseStatus::InvalidEncoding
+        );
+
+        let mut request = get_request();
+        request.header.keep_alive = true;
+        assert_eq!(
+            request.write_to_stream(&mut mock).unwrap_err(),
+            ResponseStatus::InvalidEncoding
+        );
+        assert!(mock.buffer.is_empty());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn request_async_round_trip() {
+        let mut bytes = Vec::new();
+        get_request()
+            .write_to_async_stream(&mut bytes)
+            .await
+            .expect("Failed to write request");
+        assert_eq!(bytes, get_request_bytes());
+
+        let request = Request::read_from_async_stream(&mut bytes.as_slice(), 1000)
+            .await
+            .expect("Failed to read request");
+        let exp_req = get_request();
+        assert_eq!(request.header, exp_req.header);
+        assert_eq!(request.body, exp_req.body);
+        assert_eq!(
+            request.auth.buffer.expose_secret(),
+            exp_req.auth.buffer.expose_secret()
+        );
+
+        assert_eq!(
+            Request::read_from_async_stream(&mut bytes.as_slice(), 2)
+                .await
+                .unwrap_err(),
+            ResponseStatus::BodySizeExceedsLimit
+        );
+        assert_eq!(
+            Request::read_from_async_stream(&mut &bytes[..bytes.len() - 1], 1000)
+                .await
+                .unwrap_err(),
+            ResponseStatus::ConnectionError
+        );
+    }
+
+    #[test]
+    fn request_read_limits() {
+        let limits = ReadLimits {
+            body_len: 3,
+            auth_len: 3,
+            frame_len: get_request_bytes().len(),
+        };
+        let mut mock = test_utils::MockReadWrite {
+            buffer: get_request_bytes(),
+        };
+        let _ = Request::read_from_stream(&mut mock, limits).expect("Failed to read request");
+
+        let mut mock = test_utils::MockReadWrite {
+            buffer: get_request_bytes(),
+        };
+        assert_eq!(
+            Request::read_from_stream(
+                &mut mock,
+                ReadLimits {
+                    auth_len: 2,
+                    ..limits
+                }
+            )
+            .unwrap_err(),
+            ResponseStatus::AuthSizeExceedsLimit
+        );
+
+        let mut mock = test_utils::MockReadWrite {
+            buffer: get_request_bytes(),
+        };
+        assert_eq!(
+            Request::read_from_stream(
+                &mut mock,
+                ReadLimits {
+                    frame_len: limits.frame_len - 1,
+                    ..limits
+                }
+            )
+            .unwrap_err(),
+            ResponseStatus::FrameSizeExceedsLimit
+        );
+    }
+
+    #[test]
+    fn default_auth_len_limit() {
+        let mut bytes = get_request_bytes();
+        bytes[26..28].copy_from_slice(&u16::MAX.to_le_bytes());
+        let mut mock = test_utils::MockReadWrite { buffer: bytes };
+
+        assert_eq!(
+            Request::read_from_stream(&mut mock, 1000).unwrap_err(),
+            ResponseStatus::AuthSizeExceedsLimit
+        );
+    }
+
+    #[test]
+    fn parse_request() {
+        let mut bytes = get_request_bytes();
+        bytes.extend_from_slice(&[0x10, 0xA7]);
+
+        let view = Request::parse(&bytes).expect("Failed to parse request");
+        assert_eq!(view.header, get_request().header);
+        assert_eq!(view.body, &[0x70, 0x80, 0x90]);
+        assert_eq!(view.auth, &[0xa0, 0xb0, 0xc0]);
+        assert_eq!(view.frame_len(), bytes.len() - 2);
+        // The fields reference the input buffer.
+        assert_eq!(view.body.as_ptr(), bytes[36..].as_ptr());
+
+        let request: Request = view.into();
+        assert_eq!(request.body, get_request().body);
+
+        assert_eq!(
+            Request::parse(&bytes[..40]).unwrap_err(),
+            ResponseStatus::InvalidEncoding
+        );
+        assert_eq!(
+            Request::parse(&get_request_bytes_reserved_fields_both_not_zero()).unwrap_err(),
+            ResponseStatus::InvalidHeader
+        );
+    }
+
+    #[test]
+    fn parse_request_huge_payload() {
+        let mut bytes = get_request_bytes();
+        bytes[22..26].copy_from_slice(&u32::MAX.to_le_bytes());
+        bytes[26..28].copy_from_slice(&u16::MAX.to_le_bytes());
+
+        assert_eq!(
+            Request::parse(&bytes).unwrap_err(),
+            ResponseStatus::InvalidEncoding
+        );
+    }
+
     fn get_request() -> Request {
         let body = RequestBody::from_bytes(vec![0x70, 0x80, 0x90]);
         let auth = RequestAuth::new(vec![0xa0, 0xb0, 0xc0]);
@@ -272,6 +585,9 @@ mod tests {
             accept_type: BodyType::Protobuf,
             auth_type: AuthType::Direct,
             opcode: Opcode::Ping,
+            wire_protocol_version: WireProtocolVersion::V1_0,
+            request_id: 0,
+            keep_alive: false,
         };
         Request { header, body, auth }
     }
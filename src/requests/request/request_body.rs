@@ -5,6 +5,8 @@ use crate::requests::Result;
 use arbitrary::Arbitrary;
 use std::io::{Read, Write};
 use std::ops::{Deref, DerefMut};
+#[cfg(feature = "async")]
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use zeroize::Zeroize;
 
 /// Wrapper around the body of a request.
@@ -51,6 +53,27 @@ impl RequestBody {
         Ok(())
     }
 
+    /// Read the request body from an asynchronous stream, given the length of the content.
+    #[cfg(feature = "async")]
+    pub(super) async fn read_from_async_stream(
+        stream: &mut (impl AsyncRead + Unpin),
+        len: usize,
+    ) -> Result<RequestBody> {
+        let mut buffer = vec![0; len];
+        let _ = stream.read_exact(&mut buffer).await?;
+        Ok(RequestBody { buffer })
+    }
+
+    /// Write the request body to an asynchronous stream.
+    #[cfg(feature = "async")]
+    pub(super) async fn write_to_async_stream(
+        &self,
+        stream: &mut (impl AsyncWrite + Unpin),
+    ) -> Result<()> {
+        stream.write_all(&self.buffer).await?;
+        Ok(())
+    }
+
     /// Create a `RequestBody` from a vector of bytes.
     pub(crate) fn from_bytes(buffer: Vec<u8>) -> RequestBody {
         RequestBody { buffer }
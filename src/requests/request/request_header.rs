@@ -1,12 +1,15 @@
 // Copyright 2019 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::requests::common::wire_header_1_0::WireHeader as Raw;
+use crate::requests::common::wire_header_1_1::{WireHeader as Raw1_1, FLAG_KEEP_ALIVE};
+use crate::requests::common::{WireHeader as VersionedRaw, WireProtocolVersion};
 use crate::requests::ResponseStatus;
 use crate::requests::{AuthType, BodyType, Opcode, ProviderId};
 #[cfg(feature = "fuzz")]
 use arbitrary::Arbitrary;
+use log::error;
 use num::FromPrimitive;
-use std::convert::TryFrom;
+use std::convert::{TryFrom, TryInto};
 
 /// A native representation of the request header.
 ///
@@ -27,6 +30,13 @@ pub struct RequestHeader {
     pub auth_type: AuthType,
     /// Opcode of the operation to perform.
     pub opcode: Opcode,
+    /// Version of the wire protocol used to encode the header.
+    pub wire_protocol_version: WireProtocolVersion,
+    /// Identifier of the request, copied in the response. Must be zero in version 1.0 headers.
+    pub request_id: u64,
+    /// Whether the client intends to send further requests on the same connection. Must be
+    /// false in version 1.0 headers.
+    pub keep_alive: bool,
 }
 
 impl RequestHeader {
@@ -41,6 +51,9 @@ impl RequestHeader {
             accept_type: BodyType::Protobuf,
             auth_type: AuthType::Direct,
             opcode: Opcode::Ping,
+            wire_protocol_version: WireProtocolVersion::V1_0,
+            request_id: 0,
+            keep_alive: false,
         }
     }
 }
@@ -79,10 +92,39 @@ impl TryFrom<Raw> for RequestHeader {
             accept_type,
             auth_type,
             opcode,
+            wire_protocol_version: WireProtocolVersion::V1_0,
+            request_id: 0,
+            keep_alive: false,
         })
     }
 }
 
+/// Conversion from the raw version 1.1 to native request header.
+impl TryFrom<Raw1_1> for RequestHeader {
+    type Error = ResponseStatus;
+
+    fn try_from(header: Raw1_1) -> ::std::result::Result<Self, Self::Error> {
+        let mut native_header = RequestHeader::try_from(Raw::from(header))?;
+        native_header.wire_protocol_version = WireProtocolVersion::V1_1;
+        native_header.request_id = header.request_id;
+        native_header.keep_alive = header.keep_alive();
+
+        Ok(native_header)
+    }
+}
+
+/// Conversion from a raw header of any version to native request header.
+impl TryFrom<VersionedRaw> for RequestHeader {
+    type Error = ResponseStatus;
+
+    fn try_from(header: VersionedRaw) -> ::std::result::Result<Self, Self::Error> {
+        match header {
+            VersionedRaw::V1_0(header) => header.try_into(),
+            VersionedRaw::V1_1(header) => header.try_into(),
+        }
+    }
+}
+
 /// Conversion from native to raw request header.
 ///
 /// This is required in order to bring the contents of the header in a state
@@ -105,3 +147,38 @@ impl From<RequestHeader> for Raw {
         }
     }
 }
+
+/// Conversion from native to raw request header, in the version of the wire protocol chosen
+/// in the native header.
+///
+/// The conversion fails with `ResponseStatus::InvalidEncoding` if a field that does not exist in
+/// the chosen version is set, instead of dropping it.
+impl TryFrom<RequestHeader> for VersionedRaw {
+    type Error = ResponseStatus;
+
+    fn try_from(header: RequestHeader) -> ::std::result::Result<Self, Self::Error> {
+        match header.wire_protocol_version {
+            WireProtocolVersion::V1_0 => {
+                if header.request_id != 0 || header.keep_alive {
+                    error!("The request identifier and keep-alive flag can not be sent in version 1.0 headers");
+                    return Err(ResponseStatus::InvalidEncoding);
+                }
+                Ok(VersionedRaw::V1_0(header.into()))
+            }
+            WireProtocolVersion::V1_1 => {
+                let mut raw_header = Raw1_1::from_1_0(header.into(), header.request_id);
+                if header.keep_alive {
+                    raw_header.flags |= FLAG_KEEP_ALIVE;
+                }
+                Ok(VersionedRaw::V1_1(raw_header))
+            }
+            version => {
+                error!(
+                    "Wire protocol version {}.{} is not supported",
+                    version.major, version.minor
+                );
+                Err(ResponseStatus::WireProtocolVersionNotSupported)
+            }
+        }
+    }
+}
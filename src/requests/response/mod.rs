@@ -2,13 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 //! Response definition
 
-use super::common::wire_header_1_0::WireHeader as Raw;
+use super::common::WireHeader as Raw;
 use super::request::RequestHeader;
-use super::ResponseStatus;
 use super::Result;
+use super::{ReadLimits, ResponseStatus};
 use log::error;
 use std::convert::{TryFrom, TryInto};
 use std::io::{Read, Write};
+#[cfg(feature = "async")]
+use tokio::io::{AsyncRead, AsyncWrite};
 
 mod response_body;
 mod response_header;
@@ -30,6 +32,36 @@ pub struct Response {
     pub body: ResponseBody,
 }
 
+/// Borrowed view of a response frame held in a contiguous buffer.
+///
+/// The body references the buffer the response was parsed from.
+#[derive(Debug, PartialEq)]
+pub struct ResponseView<'a> {
+    /// Header of the response, containing the response status.
+    pub header: ResponseHeader,
+    /// Bytes of the response body.
+    pub body: &'a [u8],
+    frame_len: usize,
+}
+
+impl ResponseView<'_> {
+    /// Number of bytes of the buffer spanned by the response frame. Any following bytes belong
+    /// to the next frame.
+    pub fn frame_len(&self) -> usize {
+        self.frame_len
+    }
+}
+
+/// Copy the body of the view to get an owned response.
+impl From<ResponseView<'_>> for Response {
+    fn from(view: ResponseView<'_>) -> Self {
+        Response {
+            header: view.header,
+            body: ResponseBody::from_bytes(view.body.to_vec()),
+        }
+    }
+}
+
 impl Response {
     /// Create a response with empty header and empty body.
     fn new() -> Response {
@@ -61,16 +93,18 @@ impl Response {
 
     /// Serialise response and write it to given stream.
     ///
-    /// Header is converted to a raw format before serializing.
+    /// Header is converted to a raw format before serializing, in the wire protocol version
+    /// chosen in the header.
     ///
     /// # Errors
     /// - if writing any of the subfields (header or body) fails, then
-    /// `ResponseStatus::ConnectionError` is returned.
+    ///   `ResponseStatus::ConnectionError` is returned.
     /// - if encoding any of the fields in the header fails, then
-    /// `ResponseStatus::InvalidEncoding` is returned.
+    ///   `ResponseStatus::InvalidEncoding` is returned.
+    /// - if the wire protocol version chosen in the header is not supported, then
+    ///   `ResponseStatus::WireProtocolVersionNotSupported` is returned.
     pub fn write_to_stream(self, stream: &mut impl Write) -> Result<()> {
-        let mut raw_header: Raw = self.header.into();
-        raw_header.body_len = u32::try_from(self.body.len())?;
+        let raw_header = self.raw_header()?;
 
         raw_header.write_to_stream(stream)?;
         self.body.write_to_stream(stream)?;
@@ -80,35 +114,112 @@ impl Response {
 
     /// Deserialise response from given stream.
     ///
-    /// The `body_len_limit` parameter allows the interface client to reject requests that are
-    /// longer than a predefined limit. The length limit is in bytes.
+    /// The `limits` parameter allows the interface client to reject responses that are
+    /// longer than predefined limits, before allocating memory for them. A length in bytes can
+    /// be passed to only limit the body length. Responses do not have an authentication field,
+    /// so its limit is not used.
     ///
     /// # Errors
     /// - if reading any of the subfields (header or body) fails, the
-    /// corresponding `ResponseStatus` will be returned.
-    /// - if the request body size specified in the header is larger than the limit passed as
-    /// a parameter, `BodySizeExceedsLimit` will be returned.
-    pub fn read_from_stream(stream: &mut impl Read, body_len_limit: usize) -> Result<Response> {
+    ///   corresponding `ResponseStatus` will be returned.
+    /// - if the response body size specified in the header is larger than the limit passed as
+    ///   a parameter, `BodySizeExceedsLimit` will be returned.
+    /// - if the response frame size is larger than the limit passed as a parameter,
+    ///   `FrameSizeExceedsLimit` will be returned.
+    pub fn read_from_stream(
+        stream: &mut impl Read,
+        limits: impl Into<ReadLimits>,
+    ) -> Result<Response> {
         let raw_header = Raw::read_from_stream(stream)?;
-        let body_len = usize::try_from(raw_header.body_len)?;
-        if body_len > body_len_limit {
+        let (body_len, _) = raw_header.checked_payload_len(&limits.into(), false)?;
+        let body = ResponseBody::read_from_stream(stream, body_len)?;
+
+        Ok(Response {
+            header: raw_header.try_into()?,
+            body,
+        })
+    }
+
+    /// Parse a response from the start of a contiguous buffer, without copying the body.
+    ///
+    /// The buffer can contain bytes following the response frame, whose length is given by
+    /// `ResponseView::frame_len`. As the whole frame is already in memory, no limit is put on
+    /// the body length: it is up to the caller to check the length of the body.
+    ///
+    /// # Errors
+    /// - if the buffer is too short to contain the response frame,
+    ///   `ResponseStatus::InvalidEncoding` is returned.
+    /// - otherwise, the same errors as `read_from_stream` are returned.
+    pub fn parse(bytes: &[u8]) -> Result<ResponseView<'_>> {
+        let (raw_header, hdr_len) = Raw::parse(bytes)?;
+        let body_len = usize::try_from(raw_header.body_len())?;
+        let frame_len = hdr_len.checked_add(body_len).ok_or_else(|| {
+            error!("Response frame length overflows");
+            ResponseStatus::InvalidEncoding
+        })?;
+        let payload = &bytes[hdr_len..];
+        if payload.len() < body_len {
             error!(
-                "Request body length ({}) bigger than the limit given ({}).",
-                body_len, body_len_limit
+                "Buffer of {} bytes too short for a response body of {} bytes",
+                payload.len(),
+                body_len
             );
-            return Err(ResponseStatus::BodySizeExceedsLimit);
+            return Err(ResponseStatus::InvalidEncoding);
         }
-        let body = ResponseBody::read_from_stream(stream, body_len)?;
+
+        Ok(ResponseView {
+            header: raw_header.try_into()?,
+            body: &payload[..body_len],
+            frame_len,
+        })
+    }
+
+    /// Serialise response and write it to given asynchronous stream.
+    ///
+    /// # Errors
+    /// The same errors as `write_to_stream` are returned.
+    #[cfg(feature = "async")]
+    pub async fn write_to_async_stream(self, stream: &mut (impl AsyncWrite + Unpin)) -> Result<()> {
+        let raw_header = self.raw_header()?;
+
+        raw_header.write_to_async_stream(stream).await?;
+        self.body.write_to_async_stream(stream).await?;
+
+        Ok(())
+    }
+
+    /// Deserialise response from given asynchronous stream.
+    ///
+    /// The `limits` parameter is used as in `read_from_stream`.
+    ///
+    /// # Errors
+    /// The same errors as `read_from_stream` are returned.
+    #[cfg(feature = "async")]
+    pub async fn read_from_async_stream(
+        stream: &mut (impl AsyncRead + Unpin),
+        limits: impl Into<ReadLimits>,
+    ) -> Result<Response> {
+        let raw_header = Raw::read_from_async_stream(stream).await?;
+        let (body_len, _) = raw_header.checked_payload_len(&limits.into(), false)?;
+        let body = ResponseBody::read_from_async_stream(stream, body_len).await?;
 
         Ok(Response {
             header: raw_header.try_into()?,
             body,
         })
     }
+
+    /// Convert the header to its raw format, filling in the body length.
+    fn raw_header(&self) -> Result<Raw> {
+        let mut raw_header = Raw::try_from(self.header)?;
+        raw_header.set_body_len(u32::try_from(self.body.len())?);
+        Ok(raw_header)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::super::common::WireProtocolVersion;
     use super::super::utils::tests as test_utils;
     use super::super::{BodyType, Opcode, ProviderId, ResponseStatus};
     use super::*;
@@ -185,6 +296,108 @@ mod tests {
         );
     }
 
+    #[test]
+    fn response_1_1_round_trip() {
+        let mut mock = test_utils::MockReadWrite { buffer: Vec::new() };
+        let mut response = get_response();
+        response.header.wire_protocol_version = WireProtocolVersion::V1_1;
+        response.header.request_id = 0x42;
+
+        response
+            .write_to_stream(&mut mock)
+            .expect("Failed to write response");
+        assert_eq!(mock.buffer.len(), get_response_bytes().len() + 8);
+
+        let read_response =
+            Response::read_from_stream(&mut mock, 1000).expect("Failed to read response");
+        let mut expected = get_response();
+        expected.header.wire_protocol_version = WireProtocolVersion::V1_1;
+        expected.header.request_id = 0x42;
+        assert_eq!(read_response, expected);
+    }
+
+    #[test]
+    fn response_1_1_fields_in_1_0_header() {
+        let mut mock = test_utils::MockReadWrite { buffer: Vec::new() };
+        let mut response = get_response();
+        response.header.request_id = 0x42;
+        assert_eq!(
+            response.write_to_stream(&mut mock).unwrap_err(),
+            ResponseStatus::InvalidEncoding
+        );
+
+        let mut response = get_response();
+        response.header.keep_alive = true;
+        assert_eq!(
+            response.write_to_stream(&mut mock).unwrap_err(),
+            ResponseStatus::InvalidEncoding
+        );
+        assert!(mock.buffer.is_empty());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn response_async_round_trip() {
+        let mut bytes = Vec::new();
+        get_response()
+            .write_to_async_stream(&mut bytes)
+            .await
+            .expect("Failed to write response");
+        assert_eq!(bytes, get_response_bytes());
+
+        let response = Response::read_from_async_stream(&mut bytes.as_slice(), 1000)
+            .await
+            .expect("Failed to read response");
+        assert_eq!(response, get_response());
+
+        assert_eq!(
+            Response::read_from_async_stream(&mut bytes.as_slice(), 0)
+                .await
+                .unwrap_err(),
+            ResponseStatus::BodySizeExceedsLimit
+        );
+    }
+
+    #[test]
+    fn frame_too_large() {
+        let mut mock = test_utils::MockReadWrite {
+            buffer: get_response_bytes(),
+        };
+        let limits = ReadLimits {
+            body_len: 1000,
+            auth_len: 0,
+            frame_len: get_response_bytes().len() - 1,
+        };
+
+        assert_eq!(
+            Response::read_from_stream(&mut mock, limits).unwrap_err(),
+            ResponseStatus::FrameSizeExceedsLimit
+        );
+    }
+
+    #[test]
+    fn parse_response() {
+        let bytes = get_response_bytes();
+
+        let view = Response::parse(&bytes).expect("Failed to parse response");
+        assert_eq!(view.header, get_response().header);
+        assert_eq!(view.body, &[0x70, 0x80, 0x90]);
+        assert_eq!(view.frame_len(), bytes.len());
+        assert_eq!(Response::from(view), get_response());
+
+        assert_eq!(
+            Response::parse(&bytes[..bytes.len() - 1]).unwrap_err(),
+            ResponseStatus::InvalidEncoding
+        );
+
+        let mut bytes = get_response_bytes();
+        bytes[22..26].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(
+            Response::parse(&bytes).unwrap_err(),
+            ResponseStatus::InvalidEncoding
+        );
+    }
+
     fn get_response() -> Response {
         let body = ResponseBody::from_bytes(vec![0x70, 0x80, 0x90]);
         let header = ResponseHeader {
@@ -193,6 +406,9 @@ mod tests {
             content_type: BodyType::Protobuf,
             opcode: Opcode::Ping,
             status: ResponseStatus::Success,
+            wire_protocol_version: WireProtocolVersion::V1_0,
+            request_id: 0,
+            keep_alive: false,
         };
         Response { header, body }
     }
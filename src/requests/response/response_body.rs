@@ -4,6 +4,8 @@ use super::Result;
 use serde::{Deserialize, Serialize};
 use std::io::{Read, Write};
 use std::ops::{Deref, DerefMut};
+#[cfg(feature = "async")]
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use zeroize::Zeroize;
 
 /// Wrapper around the body of a response.
@@ -47,6 +49,27 @@ impl ResponseBody {
         Ok(())
     }
 
+    /// Read the response body from an asynchronous stream, given the length of the content.
+    #[cfg(feature = "async")]
+    pub(super) async fn read_from_async_stream(
+        stream: &mut (impl AsyncRead + Unpin),
+        len: usize,
+    ) -> Result<ResponseBody> {
+        let mut buffer = vec![0; len];
+        let _ = stream.read_exact(&mut buffer).await?;
+        Ok(ResponseBody { buffer })
+    }
+
+    /// Write the response body to an asynchronous stream.
+    #[cfg(feature = "async")]
+    pub(super) async fn write_to_async_stream(
+        &self,
+        stream: &mut (impl AsyncWrite + Unpin),
+    ) -> Result<()> {
+        stream.write_all(&self.buffer).await?;
+        Ok(())
+    }
+
     /// Create a `ResponseBody` from a vector of bytes.
     pub(crate) fn from_bytes(buffer: Vec<u8>) -> ResponseBody {
         ResponseBody { buffer }
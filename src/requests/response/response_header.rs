@@ -1,9 +1,12 @@
 // Copyright 2019 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::requests::common::wire_header_1_0::WireHeader as Raw;
+use crate::requests::common::wire_header_1_1::{WireHeader as Raw1_1, FLAG_KEEP_ALIVE};
+use crate::requests::common::{WireHeader as VersionedRaw, WireProtocolVersion};
 use crate::requests::{BodyType, Opcode, ProviderId, ResponseStatus, Result};
+use log::error;
 use num::FromPrimitive;
-use std::convert::TryFrom;
+use std::convert::{TryFrom, TryInto};
 
 /// A native representation of the response header.
 ///
@@ -21,6 +24,13 @@ pub struct ResponseHeader {
     pub opcode: Opcode,
     /// Response status of the request.
     pub status: ResponseStatus,
+    /// Version of the wire protocol used to encode the header.
+    pub wire_protocol_version: WireProtocolVersion,
+    /// Identifier of the request this response is for. Must be zero in version 1.0 headers.
+    pub request_id: u64,
+    /// Whether the service keeps the connection open for further requests. Must be false in
+    /// version 1.0 headers.
+    pub keep_alive: bool,
 }
 
 impl ResponseHeader {
@@ -32,6 +42,9 @@ impl ResponseHeader {
             content_type: BodyType::Protobuf,
             opcode: Opcode::Ping,
             status: ResponseStatus::Success,
+            wire_protocol_version: WireProtocolVersion::V1_0,
+            request_id: 0,
+            keep_alive: false,
         }
     }
 }
@@ -69,10 +82,39 @@ impl TryFrom<Raw> for ResponseHeader {
             content_type,
             opcode,
             status,
+            wire_protocol_version: WireProtocolVersion::V1_0,
+            request_id: 0,
+            keep_alive: false,
         })
     }
 }
 
+/// Conversion from the raw version 1.1 to native response header.
+impl TryFrom<Raw1_1> for ResponseHeader {
+    type Error = ResponseStatus;
+
+    fn try_from(header: Raw1_1) -> Result<ResponseHeader> {
+        let mut native_header = ResponseHeader::try_from(Raw::from(header))?;
+        native_header.wire_protocol_version = WireProtocolVersion::V1_1;
+        native_header.request_id = header.request_id;
+        native_header.keep_alive = header.keep_alive();
+
+        Ok(native_header)
+    }
+}
+
+/// Conversion from a raw header of any version to native response header.
+impl TryFrom<VersionedRaw> for ResponseHeader {
+    type Error = ResponseStatus;
+
+    fn try_from(header: VersionedRaw) -> Result<ResponseHeader> {
+        match header {
+            VersionedRaw::V1_0(header) => header.try_into(),
+            VersionedRaw::V1_1(header) => header.try_into(),
+        }
+    }
+}
+
 /// Conversion from native to raw response header.
 ///
 /// This is required in order to bring the contents of the header in a state
@@ -95,3 +137,38 @@ impl From<ResponseHeader> for Raw {
         }
     }
 }
+
+/// Conversion from native to raw response header, in the version of the wire protocol chosen
+/// in the native header.
+///
+/// The conversion fails with `ResponseStatus::InvalidEncoding` if a field that does not exist in
+/// the chosen version is set, instead of dropping it.
+impl TryFrom<ResponseHeader> for VersionedRaw {
+    type Error = ResponseStatus;
+
+    fn try_from(header: ResponseHeader) -> Result<VersionedRaw> {
+        match header.wire_protocol_version {
+            WireProtocolVersion::V1_0 => {
+                if header.request_id != 0 || header.keep_alive {
+                    error!("The request identifier and keep-alive flag can not be sent in version 1.0 headers");
+                    return Err(ResponseStatus::InvalidEncoding);
+                }
+                Ok(VersionedRaw::V1_0(header.into()))
+            }
+            WireProtocolVersion::V1_1 => {
+                let mut raw_header = Raw1_1::from_1_0(header.into(), header.request_id);
+                if header.keep_alive {
+                    raw_header.flags |= FLAG_KEEP_ALIVE;
+                }
+                Ok(VersionedRaw::V1_1(raw_header))
+            }
+            version => {
+                error!(
+                    "Wire protocol version {}.{} is not supported",
+                    version.major, version.minor
+                );
+                Err(ResponseStatus::WireProtocolVersionNotSupported)
+            }
+        }
+    }
+}
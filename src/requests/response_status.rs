@@ -58,6 +58,16 @@ pub enum ResponseStatus {
     BodySizeExceedsLimit = 20,
     /// The operation requires admin privilege
     AdminOperation = 21,
+    /// Authentication length specified in the header is above defined limit
+    ///
+    /// Provisional code, not yet allocated in the status code page of the book. Clients
+    /// predating it fail to decode it as an unknown status.
+    AuthSizeExceedsLimit = 22,
+    /// Frame length specified in the header is above defined limit
+    ///
+    /// Provisional code, not yet allocated in the status code page of the book. Clients
+    /// predating it fail to decode it as an unknown status.
+    FrameSizeExceedsLimit = 23,
     /// An error occurred that does not correspond to any defined failure cause
     PsaErrorGenericError = 1132,
     /// The requested operation or a parameter is not supported by this implementation
@@ -183,6 +193,18 @@ impl fmt::Display for ResponseStatus {
             ResponseStatus::AdminOperation => {
                 write!(f, "the operation requires admin privilege")
             }
+            ResponseStatus::AuthSizeExceedsLimit => {
+                write!(
+                    f,
+                    "authentication length specified in the header is above defined limit"
+                )
+            }
+            ResponseStatus::FrameSizeExceedsLimit => {
+                write!(
+                    f,
+                    "frame length specified in the header is above defined limit"
+                )
+            }
             ResponseStatus::PsaErrorGenericError => {
                 write!(
                     f,
@@ -23,12 +23,15 @@ macro_rules! get_from_stream {
     };
 }
 
+/// Mock streams used in tests.
 #[cfg(test)]
 pub mod tests {
     use std::io::{Error, ErrorKind, Read, Result, Write};
 
+    /// Stream reading from and writing to an in-memory buffer.
     #[derive(Debug)]
     pub struct MockReadWrite {
+        /// Bytes left to read, followed by the bytes written.
         pub buffer: Vec<u8>,
     }
 
@@ -55,6 +58,7 @@ pub mod tests {
         }
     }
 
+    /// Stream failing on every read or write.
     #[derive(Copy, Clone, Debug)]
     pub struct MockFailReadWrite;
 
@@ -20,6 +20,8 @@ git submodule update --init
 RUST_BACKTRACE=1 cargo build
 RUST_BACKTRACE=1 cargo build --features testing
 RUST_BACKTRACE=1 cargo build --features regenerate-protobuf
+RUST_BACKTRACE=1 cargo build --features async
+RUST_BACKTRACE=1 cargo build --features codec
 
 #################
 # Static checks #
@@ -38,5 +40,7 @@ fi
 # Unit tests and doc tests #
 ############################
 RUST_BACKTRACE=1 cargo test
+RUST_BACKTRACE=1 cargo test --features async
+RUST_BACKTRACE=1 cargo test --features codec
 
 cargo clean